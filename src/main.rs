@@ -1,86 +1,256 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    account, all, bundle, dashboard, difficulty, doctor, download, examples, leaderboard, puzzles,
+    read, scaffold, solve, stats, status, test_day, time, watch,
+};
+use advent_of_code::template::{config, error::Error};
 use args::{parse, AppArguments};
+use std::process;
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
-use std::process;
+use advent_of_code::template::commands::today;
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{
+        commands::{
+            account::AccountCommand, difficulty::DifficultyOptions,
+            leaderboard::LeaderboardOptions, puzzles::PuzzlesCommand, scaffold::ScaffoldOptions,
+        },
+        config, module_template, DaySelection, DaySelectionError, InputSource,
+    };
+    use std::path::PathBuf;
 
     pub enum AppArguments {
+        Account {
+            command: AccountCommand,
+        },
+        Bundle {
+            days: DaySelection,
+        },
+        Dashboard,
+        Difficulty {
+            options: DifficultyOptions,
+        },
+        Doctor,
         Download {
-            day: Day,
+            days: DaySelection,
+        },
+        Puzzles {
+            command: PuzzlesCommand,
         },
         Read {
-            day: Day,
+            days: DaySelection,
+            pager: bool,
+        },
+        Examples {
+            days: DaySelection,
+        },
+        Leaderboard {
+            id: String,
+            options: LeaderboardOptions,
         },
         Scaffold {
-            day: Day,
+            days: DaySelection,
             download: bool,
+            options: ScaffoldOptions,
         },
         Solve {
-            day: Day,
+            days: DaySelection,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            watch: bool,
+            test: bool,
+            input: InputSource,
         },
         All {
+            days: DaySelection,
+            release: bool,
+        },
+        Stats,
+        Status {
+            days: DaySelection,
+            test: bool,
+        },
+        TestDay {
+            days: DaySelection,
             release: bool,
         },
         Time {
             all: bool,
-            day: Option<Day>,
+            days: Option<DaySelection>,
             store: bool,
         },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            wait: bool,
+        },
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
+        // a broken aoc.json is reported by `run`, `doctor` still has to parse.
+        let defaults = config::load()
+            .map(|config| config.defaults.clone())
+            .unwrap_or_default();
 
         let app_args = match args.subcommand()?.as_deref() {
+            Some("account") => AppArguments::Account {
+                command: match args.subcommand()?.as_deref() {
+                    Some("add") => AccountCommand::Add {
+                        name: args.free_from_str()?,
+                        token: args.opt_free_from_str()?,
+                    },
+                    Some("list") | None => AccountCommand::List,
+                    Some("select") => AccountCommand::Select {
+                        name: args.free_from_str()?,
+                    },
+                    Some("validate") => AccountCommand::Validate {
+                        name: args.opt_free_from_str()?,
+                    },
+                    Some(x) => {
+                        return Err(format!("Unknown account command: {x}").into());
+                    }
+                },
+            },
             Some("all") => AppArguments::All {
-                release: args.contains("--release"),
+                release: args.contains("--release") || defaults.release,
+                days: args.opt_free_from_str()?.unwrap_or_else(DaySelection::all),
             },
             Some("time") => {
                 let all = args.contains("--all");
-                let store = args.contains("--store");
+                let store = args.contains("--store") || defaults.store;
 
                 AppArguments::Time {
                     all,
-                    day: args.opt_free_from_str()?,
+                    days: args.opt_free_from_str()?,
                     store,
                 }
             }
-            Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+            Some("difficulty") => AppArguments::Difficulty {
+                options: DifficultyOptions {
+                    file: args.opt_value_from_str("--file")?,
+                    cached: args.contains("--cached"),
+                },
+            },
+            Some("download") => {
+                let all = args.contains("--all");
+                let days = match args.opt_free_from_str()? {
+                    Some(days) => days,
+                    None if all => DaySelection::all(),
+                    None => return Err(DaySelectionError.into()),
+                };
+
+                AppArguments::Download { days }
+            }
+            Some("puzzles") => AppArguments::Puzzles {
+                command: match args.subcommand()?.as_deref() {
+                    Some("list") | None => PuzzlesCommand::List,
+                    Some("search") => {
+                        let mut terms: Vec<String> = vec![];
+                        while let Some(term) = args.opt_free_from_str()? {
+                            terms.push(term);
+                        }
+                        PuzzlesCommand::Search {
+                            query: terms.join(" "),
+                        }
+                    }
+                    Some(x) => {
+                        return Err(format!("Unknown puzzles command: {x}").into());
+                    }
+                },
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                days: args.free_from_str()?,
+                pager: args.contains("--pager") || defaults.pager,
+            },
+            Some("examples") => AppArguments::Examples {
+                days: args.free_from_str()?,
+            },
+            Some("leaderboard") => AppArguments::Leaderboard {
+                options: LeaderboardOptions {
+                    file: args.opt_value_from_str("--file")?,
+                    cached: args.contains("--cached"),
+                    day: args.opt_value_from_str("--day")?,
+                    missing: args.contains("--missing"),
+                    times: args.contains("--times"),
+                },
+                id: args.free_from_str()?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
                 download: args.contains("--download"),
+                options: ScaffoldOptions {
+                    template: args.opt_value_from_str("--template")?,
+                    variables: args.values_from_fn("--var", module_template::parse_variable)?,
+                },
+                days: args.free_from_str()?,
+            },
+            Some("solve") => {
+                let days: DaySelection = args.free_from_str()?;
+                let submit = args.opt_value_from_str("--submit")?;
+                let watch = args.contains("--watch");
+                let test = args.contains("--test");
+                let input = parse_input_source(&mut args)?;
+
+                if submit.is_some() && days.single().is_none() {
+                    return Err("`--submit` can only be used with a single day.".into());
+                }
+
+                if watch && (days.single().is_none() || submit.is_some()) {
+                    return Err(
+                        "`--watch` can only be used with a single day and without `--submit`."
+                            .into(),
+                    );
+                }
+
+                if test && !watch {
+                    return Err("`--test` can only be used with `--watch`.".into());
+                }
+
+                if input != InputSource::Puzzle && (submit.is_some() || watch) {
+                    return Err("`--input`, `--example` and `--stdin` cannot be combined with `--submit` or `--watch`.".into());
+                }
+
+                if matches!(input, InputSource::File(_) | InputSource::Stdin)
+                    && days.single().is_none()
+                {
+                    return Err(
+                        "`--input` and `--stdin` can only be used with a single day.".into(),
+                    );
+                }
+
+                AppArguments::Solve {
+                    days,
+                    release: args.contains("--release") || defaults.release,
+                    submit,
+                    dhat: args.contains("--dhat"),
+                    watch,
+                    test,
+                    input,
+                }
+            }
+            Some("bundle") => AppArguments::Bundle {
+                days: args.free_from_str()?,
+            },
+            Some("dashboard") => AppArguments::Dashboard,
+            Some("doctor") => AppArguments::Doctor,
+            Some("stats") => AppArguments::Stats,
+            Some("status") => AppArguments::Status {
+                test: args.contains("--test"),
+                days: args.opt_free_from_str()?.unwrap_or_else(DaySelection::all),
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
+            Some("test-day") => AppArguments::TestDay {
+                release: args.contains("--release") || defaults.release,
+                days: args.free_from_str()?,
             },
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
+            Some("today") => AppArguments::Today {
+                wait: args.contains("--wait"),
+            },
             Some(x) => {
-                eprintln!("Unknown command: {x}");
-                process::exit(1);
+                return Err(format!("Unknown command: {x}").into());
             }
             None => {
-                eprintln!("No command specified.");
-                process::exit(1);
+                return Err("No command specified.".into());
             }
         };
 
@@ -91,48 +261,142 @@ mod args {
 
         Ok(app_args)
     }
+
+    /// Parses `--input <path>`, `--example [N]` and `--stdin`.
+    fn parse_input_source(
+        args: &mut pico_args::Arguments,
+    ) -> Result<InputSource, Box<dyn std::error::Error>> {
+        let mut sources = vec![];
+
+        if let Some(path) = args.opt_value_from_str::<_, PathBuf>("--input")? {
+            sources.push(InputSource::File(path));
+        }
+
+        if args.contains("--stdin") {
+            sources.push(InputSource::Stdin);
+        }
+
+        // the number of `--example` is optional, so it is read from a copy that is only kept if one follows.
+        let mut numbered = args.clone();
+        match numbered.opt_value_from_str::<_, u8>("--example") {
+            Ok(Some(n)) => {
+                *args = numbered;
+                sources.push(InputSource::Example(Some(n)));
+            }
+            _ if args.contains("--example") => sources.push(InputSource::Example(None)),
+            Ok(None) => {}
+            Err(e) => return Err(e.into()),
+        }
+
+        if sources.len() > 1 {
+            return Err("only one of `--input`, `--example` and `--stdin` can be used.".into());
+        }
+
+        Ok(sources.pop().unwrap_or_default())
+    }
 }
 
 fn main() {
-    match parse() {
-        Err(err) => {
-            eprintln!("Error: {err}");
-            std::process::exit(1);
+    let result = match parse() {
+        Err(err) => Err(Error::Usage(format!("Error: {err}"))),
+        Ok(args) => run(args),
+    };
+
+    if let Err(err) = result {
+        eprintln!("{err}");
+        process::exit(err.exit_code());
+    }
+}
+
+fn run(args: AppArguments) -> Result<(), Error> {
+    // `doctor` reports a broken aoc.json among its other checks.
+    if !matches!(args, AppArguments::Doctor) {
+        config::load().map_err(Error::Usage)?;
+    }
+
+    match args {
+        AppArguments::Account { command } => account::handle(command),
+        AppArguments::All { days, release } => all::handle(&days.days(), release),
+        AppArguments::Time { days, all, store } => {
+            time::handle(days.map(|days| days.days()), all, store)
         }
-        Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day, download } => {
-                scaffold::handle(day);
-                if download {
-                    download::handle(day);
-                }
+        AppArguments::Bundle { days } => bundle::handle(&days.days()),
+        AppArguments::Dashboard => dashboard::handle(),
+        AppArguments::Difficulty { options } => difficulty::handle(&options),
+        AppArguments::Doctor => doctor::handle(),
+        AppArguments::Download { days } => match days.single() {
+            Some(day) => download::handle(day),
+            None => download::handle_many(days.days().into_iter()),
+        },
+        AppArguments::Puzzles { command } => puzzles::handle(command),
+        AppArguments::Read { days, pager } => {
+            for day in days.days() {
+                read::handle(day, pager)?;
             }
-            AppArguments::Solve {
-                day,
-                release,
-                dhat,
-                submit,
-            } => solve::handle(day, release, dhat, submit),
-            #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
+            Ok(())
+        }
+        AppArguments::Examples { days } => {
+            for day in days.days() {
+                examples::handle(day)?;
+            }
+            Ok(())
+        }
+        AppArguments::Leaderboard { id, options } => leaderboard::handle(&id, &options),
+        AppArguments::Scaffold {
+            days,
+            download,
+            options,
+        } => match days.single() {
+            // download first, so that the template can match the shape of the input.
+            Some(day) => {
+                let downloaded = match download {
+                    true => download::handle(day),
+                    false => Ok(()),
+                };
+                scaffold::handle(day, &options)?;
+                downloaded
+            }
+            None => {
+                let downloaded = match download {
+                    true => download::handle_many(days.days().into_iter()),
+                    false => Ok(()),
                 };
+                scaffold::handle_many(days.days().into_iter(), &options)?;
+                downloaded
             }
         },
-    };
+        AppArguments::Solve {
+            days,
+            release,
+            dhat,
+            submit,
+            watch,
+            test,
+            input,
+        } => {
+            if let (true, Some(day)) = (watch, days.single()) {
+                return watch::handle(day, release, test);
+            }
+
+            // keep going after a failing day, but report all of them at the end.
+            let mut failed = vec![];
+            for day in days.days() {
+                match solve::handle(day, release, dhat, submit, &input) {
+                    Err(Error::Solution(days)) => failed.extend(days),
+                    result => result?,
+                }
+            }
+
+            if failed.is_empty() {
+                Ok(())
+            } else {
+                Err(Error::Solution(failed))
+            }
+        }
+        AppArguments::Stats => stats::handle(),
+        AppArguments::Status { days, test } => status::handle(&days.days(), test),
+        AppArguments::TestDay { days, release } => test_day::handle(&days.days(), release),
+        #[cfg(feature = "today")]
+        AppArguments::Today { wait } => today::handle(wait),
+    }
 }
@@ -0,0 +1,206 @@
+/// Module that stores named session tokens, so several people can use one checkout with their own accounts.
+/// The accounts file lives outside the repository, by default in `~/.adventofcode.accounts.json`.
+use std::{collections::HashMap, fs, io::Error, path::Path, str::FromStr};
+use tinyjson::JsonValue;
+
+/// A named session token.
+#[derive(Clone, Debug)]
+pub struct Account {
+    pub name: String,
+    pub token: String,
+}
+
+/// The known accounts and the one selected by default.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Accounts {
+    pub selected: Option<String>,
+    pub data: Vec<Account>,
+}
+
+/// Account names are used as directory names, so only allow a safe set of characters.
+pub fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+impl Accounts {
+    /// Dehydrate accounts to a JSON file. The file is only readable by the current user.
+    pub fn store_file(&self, path: &Path) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        let mut file = options.open(path)?;
+        // the mode only applies to new files, so tighten an existing one before the tokens are written.
+        #[cfg(unix)]
+        file.set_permissions(std::os::unix::fs::PermissionsExt::from_mode(0o600))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate accounts from a JSON file. If not present, returns no accounts.
+    pub fn read_from_file(path: &Path) -> Self {
+        if !path.exists() {
+            return Accounts::default();
+        }
+
+        let s = fs::read_to_string(path)
+            .map_err(|x| x.to_string())
+            .and_then(Accounts::try_from);
+
+        match s {
+            Ok(accounts) => accounts,
+            Err(e) => {
+                eprintln!("Failed to read \"{}\": {e}", path.display());
+                Accounts::default()
+            }
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Account> {
+        self.data.iter().find(|a| a.name == name)
+    }
+
+    /// Adds an account or replaces the token of an existing one.
+    pub fn upsert(&mut self, name: &str, token: &str) {
+        match self.data.iter_mut().find(|a| a.name == name) {
+            Some(account) => account.token = token.to_string(),
+            None => {
+                self.data.push(Account {
+                    name: name.to_string(),
+                    token: token.to_string(),
+                });
+                self.data.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Accounts> for JsonValue {
+    fn from(value: Accounts) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "selected".into(),
+            value.selected.map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Accounts {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let selected = match json.get("selected") {
+            None | Some(JsonValue::Null) => None,
+            Some(JsonValue::String(s)) => Some(s.clone()),
+            Some(_) => return Err("expected `json.selected` to be null or string.".into()),
+        };
+
+        let data = json
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?
+            .iter()
+            .map(Account::try_from)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Accounts { selected, data })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Account> for JsonValue {
+    fn from(value: &Account) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        map.insert("name".into(), JsonValue::String(value.name.clone()));
+        map.insert("token".into(), JsonValue::String(value.token.clone()));
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Account {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected account to be a JSON object.")?;
+
+        let name = json
+            .get("name")
+            .and_then(|v| v.get::<String>())
+            .filter(|name| is_valid_name(name))
+            .ok_or("Expected account.name to be a valid name.")?;
+
+        let token = json
+            .get("token")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected account.token to be a string.")?;
+
+        Ok(Account {
+            name: name.clone(),
+            token: token.clone(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{is_valid_name, Accounts};
+
+    #[test]
+    fn validates_names() {
+        assert!(is_valid_name("alice"));
+        assert!(is_valid_name("team-b_2"));
+        assert!(!is_valid_name(""));
+        assert!(!is_valid_name("../alice"));
+        assert!(!is_valid_name("a b"));
+    }
+
+    #[test]
+    fn upserts_accounts() {
+        let mut accounts = Accounts::default();
+        accounts.upsert("bob", "1");
+        accounts.upsert("alice", "2");
+        accounts.upsert("bob", "3");
+
+        assert_eq!(accounts.data.len(), 2);
+        assert_eq!(accounts.data[0].name, "alice");
+        assert_eq!(accounts.get("bob").map(|a| a.token.as_str()), Some("3"));
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let mut accounts = Accounts::default();
+        accounts.upsert("alice", "abc");
+        accounts.selected = Some("alice".into());
+
+        let json = tinyjson::JsonValue::from(accounts).stringify().unwrap();
+        let parsed = Accounts::try_from(json).unwrap();
+        assert_eq!(parsed.selected.as_deref(), Some("alice"));
+        assert_eq!(parsed.get("alice").map(|a| a.token.as_str()), Some("abc"));
+    }
+}
@@ -0,0 +1,256 @@
+use std::{collections::HashMap, fs, io::Error, path::Path, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{config, puzzle::Puzzle, Day};
+
+fn get_file_path() -> String {
+    config::get().account_data_file("answers.json")
+}
+
+/// Represents the verified answers for a single day.
+#[derive(Clone, Debug)]
+pub struct Answer {
+    pub day: Day,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// Represents the verified answers for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<Answer>,
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        config::store_json(&get_file_path(), &JsonValue::from(self.clone()))
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns empty answers.
+    pub fn read_from_file() -> Self {
+        let path = get_file_path();
+        if !Path::new(&path).exists() {
+            return Answers::default();
+        }
+
+        let s = fs::read_to_string(&path)
+            .map_err(|x| x.to_string())
+            .and_then(Answers::try_from);
+
+        match s {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("{e}");
+                Answers::default()
+            }
+        }
+    }
+
+    /// Returns the verified answer of a part.
+    pub fn get(&self, day: Day, part: u8) -> Option<&str> {
+        let answer = self.data.iter().find(|a| a.day == day)?;
+        match part {
+            1 => answer.part_1.as_deref(),
+            2 => answer.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Stores the verified answer of a part. Returns `true` if the stored value changed.
+    pub fn set(&mut self, day: Day, part: u8, value: &str) -> bool {
+        if self.get(day, part) == Some(value) {
+            return false;
+        }
+
+        let index = match self.data.iter().position(|a| a.day == day) {
+            Some(index) => index,
+            None => {
+                self.data.push(Answer {
+                    day,
+                    part_1: None,
+                    part_2: None,
+                });
+                self.data.sort_unstable_by_key(|a| a.day);
+                self.data.iter().position(|a| a.day == day).unwrap()
+            }
+        };
+
+        let answer = &mut self.data[index];
+        match part {
+            1 => answer.part_1 = Some(value.to_string()),
+            2 => answer.part_2 = Some(value.to_string()),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Copies the answers confirmed in a puzzle description into the store.
+    /// Returns the parts that were added or changed.
+    pub fn harvest(&mut self, day: Day, puzzle: &Puzzle) -> Vec<u8> {
+        (1..=2)
+            .filter(|part| {
+                puzzle
+                    .confirmed_answer(*part)
+                    .is_some_and(|value| self.set(day, *part, &value))
+            })
+            .collect()
+    }
+}
+
+/// Reads the downloaded puzzle description of a day and stores the answers it confirms.
+pub fn harvest(day: Day) {
+    let Ok(puzzle) = Puzzle::read(day) else {
+        return;
+    };
+
+    let mut answers = Answers::read_from_file();
+    let harvested = answers.harvest(day, &puzzle);
+    if harvested.is_empty() {
+        return;
+    }
+
+    match answers.store_file() {
+        Ok(()) => {
+            for part in harvested {
+                println!(
+                    "🎄 Stored verified answer for part {part}: {}",
+                    answers.get(day, part).unwrap_or_default()
+                );
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to store verified answers: {e}");
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(Answer::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answer> for JsonValue {
+    fn from(value: &Answer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        for (key, answer) in [("part_1", &value.part_1), ("part_2", &value.part_2)] {
+            map.insert(
+                key.into(),
+                answer.clone().map_or(JsonValue::Null, JsonValue::String),
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Answer {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answer to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answer.day to be a Day struct.")?;
+
+        let part_1 = json
+            .get("part_1")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_1 to be null or string.")?;
+
+        let part_2 = json
+            .get("part_2")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_2 to be null or string.")?;
+
+        Ok(Answer {
+            day,
+            part_1: part_1.cloned(),
+            part_2: part_2.cloned(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Answers;
+    use crate::{day, template::puzzle::Puzzle};
+
+    #[test]
+    fn harvests_confirmed_answers() {
+        let puzzle = Puzzle::parse("Your puzzle answer was `42`.\n\n--- Part Two ---\n");
+        let mut answers = Answers::default();
+
+        assert_eq!(answers.harvest(day!(3), &puzzle), vec![1]);
+        assert_eq!(answers.get(day!(3), 1), Some("42"));
+        assert_eq!(answers.get(day!(3), 2), None);
+
+        // harvesting the same page again does not report changes.
+        assert!(answers.harvest(day!(3), &puzzle).is_empty());
+    }
+
+    #[test]
+    fn keeps_days_sorted() {
+        let mut answers = Answers::default();
+        answers.set(day!(5), 1, "a");
+        answers.set(day!(2), 2, "b");
+        assert_eq!(answers.data[0].day, day!(2));
+        assert_eq!(answers.data[1].day, day!(5));
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let mut answers = Answers::default();
+        answers.set(day!(13), 1, "7,3");
+        let json = tinyjson::JsonValue::from(answers).stringify().unwrap();
+        let parsed = Answers::try_from(json).unwrap();
+        assert_eq!(parsed.get(day!(13), 1), Some("7,3"));
+        assert_eq!(parsed.get(day!(13), 2), None);
+    }
+}
@@ -1,16 +1,19 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
     fmt::Display,
+    fs,
     process::{Command, Output, Stdio},
 };
 
-use crate::template::Day;
+use crate::template::{config, Day};
 
 #[derive(Debug)]
 pub enum AocCommandError {
     CommandNotFound,
     CommandNotCallable,
     BadExitStatus(Output),
+    /// The selected account is missing from the accounts file.
+    UnknownAccount(String),
 }
 
 impl Display for AocCommandError {
@@ -21,6 +24,10 @@ impl Display for AocCommandError {
             AocCommandError::BadExitStatus(_) => {
                 write!(f, "aoc-cli exited with a non-zero status.")
             }
+            AocCommandError::UnknownAccount(name) => write!(
+                f,
+                "Unknown account \"{name}\". Add it with `cargo account add {name}`, select another one or unset AOC_ACCOUNT."
+            ),
         }
     }
 }
@@ -34,6 +41,7 @@ pub fn check() -> Result<(), AocCommandError> {
 }
 
 pub fn read(day: Day) -> Result<Output, AocCommandError> {
+    check_account()?;
     let puzzle_path = get_puzzle_path(day);
 
     let args = build_args(
@@ -50,8 +58,10 @@ pub fn read(day: Day) -> Result<Output, AocCommandError> {
 }
 
 pub fn download(day: Day) -> Result<Output, AocCommandError> {
+    check_account()?;
     let input_path = get_input_path(day);
     let puzzle_path = get_puzzle_path(day);
+    create_folders();
 
     let args = build_args(
         "download",
@@ -65,6 +75,7 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
         day,
     );
 
+    print_session();
     let output = call_aoc_cli(&args)?;
     println!("---");
     println!("🎄 Successfully wrote input to \"{}\".", &input_path);
@@ -72,29 +83,125 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
     Ok(output)
 }
 
+/// Downloads only the puzzle description, leaving the input untouched.
+pub fn download_puzzle(day: Day) -> Result<Output, AocCommandError> {
+    check_account()?;
+    let puzzle_path = get_puzzle_path(day);
+    create_folders();
+
+    let args = build_args(
+        "download",
+        &[
+            "--overwrite".into(),
+            "--puzzle-only".into(),
+            "--puzzle-file".into(),
+            puzzle_path.to_string(),
+        ],
+        day,
+    );
+
+    print_session();
+    let output = call_aoc_cli(&args)?;
+    println!("---");
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(output)
+}
+
 pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
+    check_account()?;
+
     // workaround: the argument order is inverted for submit.
     let mut args = build_args("submit", &[], day);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
+
+    print_session();
+
+    // the response is captured so that the verdict can be recorded, then echoed.
+    let output = aoc_command(&args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|_| AocCommandError::CommandNotCallable)?;
+    print!("{}", String::from_utf8_lossy(&output.stdout));
+
+    if output.status.success() {
+        Ok(output)
+    } else {
+        Err(AocCommandError::BadExitStatus(output))
+    }
+}
+
+/// The folders of the account in use may not exist yet, e.g. for an account that was added before
+/// puzzle descriptions were kept per account.
+fn create_folders() {
+    let config = config::get();
+    for folder in [config.inputs_folder(), config.puzzles_folder()] {
+        let _ = fs::create_dir_all(folder);
+    }
 }
 
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
+pub fn get_input_path(day: Day) -> String {
+    config::get().input_path(day)
 }
 
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
+pub fn get_puzzle_path(day: Day) -> String {
+    config::get().puzzle_path(day)
 }
 
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
+/// Reads the session token of the selected account or, the same way aoc-cli does,
+/// from the `ADVENT_OF_CODE_SESSION` variable or the configured session file.
+/// Fails if the selected account is unknown, as falling back would act as someone else.
+pub fn session_token() -> Result<Option<String>, AocCommandError> {
+    check_account()?;
+
+    if let Some(account) = &config::get().account {
+        return Ok(Some(account.token.clone()));
+    }
+
+    if let Ok(token) = std::env::var("ADVENT_OF_CODE_SESSION") {
+        return Ok(Some(token.trim().to_string()));
+    }
+
+    let token = config::get()
+        .session_file_path()
+        .and_then(|path| std::fs::read_to_string(path).ok());
+    Ok(token
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty()))
+}
+
+fn check_account() -> Result<(), AocCommandError> {
+    match &config::get().unknown_account {
+        Some(name) => Err(AocCommandError::UnknownAccount(name.clone())),
+        None => Ok(()),
     }
 }
 
+/// Describes where the session token comes from, so that it is clear which account is acted as.
+pub fn session_description() -> String {
+    let config = config::get();
+
+    if let Some(account) = &config.account {
+        format!("account \"{}\"", account.name)
+    } else if std::env::var("ADVENT_OF_CODE_SESSION").is_ok() {
+        "the session from ADVENT_OF_CODE_SESSION".into()
+    } else {
+        match config.session_file_path() {
+            Some(path) => format!("the session from \"{}\"", path.display()),
+            None => "the default session of aoc-cli".into(),
+        }
+    }
+}
+
+fn print_session() {
+    println!("🎄 Acting as {}.", session_description());
+}
+
+pub fn get_year() -> Option<u16> {
+    config::get().year
+}
+
 fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
     let mut cmd_args = args.to_vec();
 
@@ -103,6 +210,18 @@ fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
         cmd_args.push(year.to_string());
     }
 
+    // only pass the session file if it is configured, so that aoc-cli's own defaults keep working.
+    // the token of a selected account is passed through the environment instead, see `aoc_command`.
+    let config = config::get();
+    if let (None, Some(_), Some(path)) = (
+        &config.account,
+        &config.session_file,
+        config.session_file_path(),
+    ) {
+        cmd_args.push("--session-file".into());
+        cmd_args.push(path.to_string_lossy().to_string());
+    }
+
     cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
 
     cmd_args
@@ -110,8 +229,7 @@ fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
 
 fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
     // println!("Calling >aoc with: {}", args.join(" "));
-    let output = Command::new("aoc")
-        .args(args)
+    let output = aoc_command(args)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
         .output()
@@ -123,3 +241,14 @@ fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
         Err(AocCommandError::BadExitStatus(output))
     }
 }
+
+fn aoc_command(args: &[String]) -> Command {
+    let mut cmd = Command::new("aoc");
+    cmd.args(args);
+
+    if let Some(account) = &config::get().account {
+        cmd.env("ADVENT_OF_CODE_SESSION", &account.token);
+    }
+
+    cmd
+}
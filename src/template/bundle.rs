@@ -0,0 +1,1021 @@
+/// Module that bundles the solution of a day into a single source file, with the helpers of `src/lib.rs` it uses inlined.
+/// Sources are split into top-level items with a small tokenizer, which is enough to tell which items a solution refers to.
+/// Items are matched by name, so a helper is kept if any item with that name is used, even in another module.
+use std::{
+    collections::BTreeSet,
+    fmt::Write as _,
+    path::{Path, PathBuf},
+};
+
+/// The name the solutions use to refer to the library.
+const CRATE_NAME: &str = "advent_of_code";
+/// The module of the library that holds the template, it is never bundled.
+const TEMPLATE_MODULE: &str = "template";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TokenKind {
+    Ident,
+    Literal,
+    Lifetime,
+    Punct,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Token<'a> {
+    kind: TokenKind,
+    text: &'a str,
+    start: usize,
+    end: usize,
+}
+
+impl Token<'_> {
+    fn is(&self, text: &str) -> bool {
+        self.text == text
+    }
+}
+
+/// Splits Rust source into tokens, skipping whitespace and comments.
+/// Multi-character operators are returned as single characters, which is all that item splitting needs.
+fn tokenize(source: &str) -> Vec<Token<'_>> {
+    let bytes = source.as_bytes();
+    let mut tokens = vec![];
+    let mut i = 0;
+
+    let ident_char = |b: u8| b.is_ascii_alphanumeric() || b == b'_' || b >= 0x80;
+
+    while i < bytes.len() {
+        let start = i;
+        let b = bytes[i];
+
+        let kind = if b.is_ascii_whitespace() {
+            i += 1;
+            continue;
+        } else if source[i..].starts_with("//") {
+            i = source[i..].find('\n').map_or(bytes.len(), |n| i + n);
+            continue;
+        } else if source[i..].starts_with("/*") {
+            // block comments nest.
+            let mut depth = 0;
+            while i < bytes.len() {
+                if source[i..].starts_with("/*") {
+                    depth += 1;
+                    i += 2;
+                } else if source[i..].starts_with("*/") {
+                    depth -= 1;
+                    i += 2;
+                    if depth == 0 {
+                        break;
+                    }
+                } else {
+                    i += 1;
+                }
+            }
+            continue;
+        } else if let Some(len) = raw_string_len(&source[i..]) {
+            i += len;
+            TokenKind::Literal
+        } else if b == b'"' || (b == b'b' && bytes.get(i + 1) == Some(&b'"')) {
+            i += if b == b'b' { 2 } else { 1 };
+            while i < bytes.len() && bytes[i] != b'"' {
+                i += if bytes[i] == b'\\' { 2 } else { 1 };
+            }
+            i += 1;
+            TokenKind::Literal
+        } else if b == b'\'' || (b == b'b' && bytes.get(i + 1) == Some(&b'\'')) {
+            let quote = if b == b'b' { i + 1 } else { i };
+            let rest = &source[quote + 1..];
+            let mut chars = rest.char_indices();
+            match chars.next() {
+                // an escaped char literal, e.g. '\n' or '\u{1F384}'.
+                Some((_, '\\')) => {
+                    i = quote + 1 + rest[2..].find('\'').map_or(rest.len(), |n| n + 3);
+                    TokenKind::Literal
+                }
+                Some((_, c)) if chars.next().is_some_and(|(_, q)| q == '\'') => {
+                    i = quote + c.len_utf8() + 2;
+                    TokenKind::Literal
+                }
+                // a lifetime or label, e.g. 'a.
+                _ => {
+                    i += 1;
+                    while i < bytes.len() && ident_char(bytes[i]) {
+                        i += 1;
+                    }
+                    TokenKind::Lifetime
+                }
+            }
+        } else if b.is_ascii_digit() {
+            while i < bytes.len()
+                && (ident_char(bytes[i])
+                    || (bytes[i] == b'.' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)))
+            {
+                i += 1;
+            }
+            TokenKind::Literal
+        } else if ident_char(b) {
+            if source[i..].starts_with("r#") {
+                i += 2;
+            }
+            while i < bytes.len() && ident_char(bytes[i]) {
+                i += 1;
+            }
+            TokenKind::Ident
+        } else {
+            i += source[i..].chars().next().map_or(1, char::len_utf8);
+            TokenKind::Punct
+        };
+
+        let end = i.min(bytes.len());
+        tokens.push(Token {
+            kind,
+            text: &source[start..end],
+            start,
+            end,
+        });
+        i = end;
+    }
+
+    tokens
+}
+
+/// Returns the length of a raw string literal like `r#"..."#` at the start of `s`.
+fn raw_string_len(s: &str) -> Option<usize> {
+    let rest = s.strip_prefix("br").or_else(|| s.strip_prefix('r'))?;
+    let hashes = rest.len() - rest.trim_start_matches('#').len();
+    let body = rest[hashes..].strip_prefix('"')?;
+    let closing = format!("\"{}", "#".repeat(hashes));
+    let end = body.find(&closing)?;
+    Some(s.len() - body.len() + end + closing.len())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Kind {
+    Fn,
+    Type,
+    Const,
+    Mod,
+    Impl {
+        self_type: Option<String>,
+        trait_name: Option<String>,
+    },
+    Use,
+    /// A macro call at item level, e.g. `solution!(13);`.
+    MacroCall(String),
+    /// An inner attribute like `#![allow(dead_code)]`.
+    InnerAttribute,
+    Other,
+}
+
+#[derive(Clone, Debug)]
+struct Item {
+    kind: Kind,
+    name: Option<String>,
+    text: String,
+    /// Every identifier in the item.
+    idents: BTreeSet<String>,
+    /// Tests and other items that only exist in test builds.
+    is_test: bool,
+    /// The parsed contents of a module.
+    module: Option<Module>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Module {
+    /// The declaration up to the body, e.g. `pub mod grid`.
+    header: String,
+    items: Vec<Item>,
+}
+
+/// Loads the source of a file, e.g. a module file next to `src/lib.rs`.
+type Loader<'a> = &'a dyn Fn(&Path) -> Option<String>;
+
+/// Parses the items of a source file. `dir` is where the files of `mod name;` declarations are looked up.
+fn parse(source: &str, dir: &Path, loader: Loader) -> Result<Vec<Item>, String> {
+    let tokens = tokenize(source);
+    let mut items = vec![];
+    let mut depth = 0usize;
+    let mut first = 0;
+    let mut text_start = 0;
+
+    for (i, token) in tokens.iter().enumerate() {
+        if token.kind == TokenKind::Punct {
+            match token.text {
+                "(" | "[" | "{" => depth += 1,
+                ")" | "]" | "}" => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+        }
+
+        if depth > 0 {
+            continue;
+        }
+
+        let item_tokens = &tokens[first..=i];
+        let ends = match token.text {
+            ";" => true,
+            "}" => ends_with_block(item_tokens),
+            "]" => item_tokens.len() > 2 && item_tokens[0].is("#") && item_tokens[1].is("!"),
+            _ => false,
+        };
+
+        if ends {
+            let text = &source[text_start..token.end];
+            items.push(parse_item(item_tokens, text, token.end, dir, loader)?);
+            first = i + 1;
+            text_start = token.end;
+        }
+    }
+
+    Ok(items)
+}
+
+/// Skips outer attributes and returns the index of the first token after them.
+fn skip_attributes(tokens: &[Token]) -> usize {
+    let mut i = 0;
+    while i + 1 < tokens.len() && tokens[i].is("#") && tokens[i + 1].is("[") {
+        let mut depth = 0;
+        i += 1;
+        while i < tokens.len() {
+            match tokens[i].text {
+                "[" => depth += 1,
+                "]" => {
+                    depth -= 1;
+                    if depth == 0 {
+                        i += 1;
+                        break;
+                    }
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+    }
+    i
+}
+
+/// Skips the visibility and qualifiers like `unsafe` and returns the index of the item keyword.
+fn skip_qualifiers(tokens: &[Token], mut i: usize) -> usize {
+    if tokens.get(i).is_some_and(|t| t.is("pub")) {
+        i += 1;
+        if tokens.get(i).is_some_and(|t| t.is("(")) {
+            while i < tokens.len() && !tokens[i].is(")") {
+                i += 1;
+            }
+            i += 1;
+        }
+    }
+
+    while let Some(token) = tokens.get(i) {
+        let next = tokens.get(i + 1);
+        match token.text {
+            "unsafe" | "async" | "default" => i += 1,
+            // `const fn`, but not `const NAME`.
+            "const" if next.is_some_and(|t| t.is("fn") || t.is("unsafe")) => i += 1,
+            // `extern "C" fn`, but not `extern crate`.
+            "extern" if next.is_some_and(|t| t.kind == TokenKind::Literal) => i += 2,
+            _ => break,
+        }
+    }
+
+    i
+}
+
+/// Whether the item ends with its `{ ... }` body, unlike e.g. `const X: Point = Point { .. };`.
+fn ends_with_block(tokens: &[Token]) -> bool {
+    let i = skip_qualifiers(tokens, skip_attributes(tokens));
+    match tokens.get(i).map(|t| t.text) {
+        Some("fn" | "impl" | "mod" | "trait" | "enum" | "struct" | "union" | "extern") => true,
+        Some("macro_rules") => true,
+        // a macro call with braces, e.g. `thread_local! { ... }`.
+        Some(_) => tokens.get(i + 1).is_some_and(|t| t.is("!")),
+        None => false,
+    }
+}
+
+fn parse_item(
+    tokens: &[Token],
+    text: &str,
+    end: usize,
+    dir: &Path,
+    loader: Loader,
+) -> Result<Item, String> {
+    let offset = end - text.len();
+    let attributes = skip_attributes(tokens);
+    let is_test = tokens[..attributes]
+        .windows(2)
+        .any(|w| w[0].is("[") && w[1].is("test"))
+        || tokens[..attributes]
+            .windows(4)
+            .any(|w| w[0].is("cfg") && w[1].is("(") && w[2].is("test") && w[3].is(")"));
+
+    let i = skip_qualifiers(tokens, attributes);
+    let keyword = tokens.get(i).map_or("", |t| t.text);
+    let next_ident = |from: usize| {
+        tokens[from..]
+            .iter()
+            .find(|t| t.kind == TokenKind::Ident && !t.is("mut"))
+            .map(|t| t.text.trim_start_matches("r#").to_string())
+    };
+
+    let (kind, name) = match keyword {
+        "#" => (Kind::InnerAttribute, None),
+        "fn" => (Kind::Fn, next_ident(i + 1)),
+        "struct" | "enum" | "union" | "trait" | "type" => (Kind::Type, next_ident(i + 1)),
+        "const" | "static" => (Kind::Const, next_ident(i + 1)),
+        "mod" => (Kind::Mod, next_ident(i + 1)),
+        "use" => (Kind::Use, None),
+        "extern" if tokens.get(i + 1).is_some_and(|t| t.is("crate")) => (Kind::Use, None),
+        "impl" => (parse_impl(&tokens[i + 1..]), None),
+        "macro_rules" => (Kind::Type, next_ident(i + 2)),
+        _ if tokens.get(i + 1).is_some_and(|t| t.is("!") || t.is(":")) => {
+            // the path of the macro, e.g. `advent_of_code::solution`.
+            let path: String = tokens[i..]
+                .iter()
+                .take_while(|t| !t.is("!"))
+                .map(|t| t.text)
+                .collect();
+            (Kind::MacroCall(path), None)
+        }
+        _ => (Kind::Other, None),
+    };
+
+    let module = match (&kind, &name) {
+        (Kind::Mod, Some(name)) => {
+            let body = tokens[i..].iter().position(|t| t.is("{")).map(|p| i + p);
+            let sub_dir = dir.join(name);
+            Some(match body {
+                Some(open) => Module {
+                    header: text[..tokens[open].start - offset].trim().to_string(),
+                    items: parse(
+                        &text[tokens[open].end - offset..tokens[tokens.len() - 1].start - offset],
+                        &sub_dir,
+                        loader,
+                    )?,
+                },
+                None => {
+                    let header = text[..tokens[tokens.len() - 1].start - offset].trim();
+                    load_module(header, name, dir, loader)?
+                }
+            })
+        }
+        _ => None,
+    };
+
+    Ok(Item {
+        kind,
+        name,
+        text: text.to_string(),
+        idents: tokens
+            .iter()
+            .filter(|t| t.kind == TokenKind::Ident)
+            .map(|t| t.text.trim_start_matches("r#").to_string())
+            .collect(),
+        is_test,
+        module,
+    })
+}
+
+/// Loads the file of a `mod name;` declaration, `name.rs` or `name/mod.rs`.
+fn load_module(header: &str, name: &str, dir: &Path, loader: Loader) -> Result<Module, String> {
+    // the template is never bundled, so it is not loaded either.
+    if name == TEMPLATE_MODULE {
+        return Ok(Module::default());
+    }
+
+    let candidates = [
+        dir.join(format!("{name}.rs")),
+        dir.join(name).join("mod.rs"),
+    ];
+    let source = candidates
+        .iter()
+        .find_map(|path| loader(path))
+        .ok_or_else(|| format!("could not find the file of module `{name}`"))?;
+
+    Ok(Module {
+        header: header.to_string(),
+        items: parse(&source, &dir.join(name), loader)?,
+    })
+}
+
+/// Reads the implemented type and trait of an `impl` block.
+fn parse_impl(tokens: &[Token]) -> Kind {
+    // skip the generics of the impl, e.g. `impl<T: Display>`.
+    let mut i = 0;
+    if tokens.first().is_some_and(|t| t.is("<")) {
+        let mut depth = 0;
+        for (j, token) in tokens.iter().enumerate() {
+            match token.text {
+                "<" => depth += 1,
+                ">" if j > 0 && tokens[j - 1].is("-") => {}
+                ">" => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 {
+                i = j + 1;
+                break;
+            }
+        }
+    }
+
+    let header: Vec<&Token> = tokens[i..]
+        .iter()
+        .take_while(|t| !t.is("{") && !t.is("where"))
+        .collect();
+    // the last segment of a path before its generics, e.g. `Grid` in `crate::grid::Grid<T>`.
+    let type_name = |tokens: &[&Token]| {
+        tokens
+            .iter()
+            .take_while(|t| !t.is("<"))
+            .filter(|t| t.kind == TokenKind::Ident && !t.is("dyn") && !t.is("for"))
+            .last()
+            .map(|t| t.text.to_string())
+    };
+
+    match header.iter().position(|t| t.is("for")) {
+        Some(pos) => Kind::Impl {
+            self_type: type_name(&header[pos + 1..]),
+            trait_name: type_name(&header[..pos]),
+        },
+        None => Kind::Impl {
+            self_type: type_name(&header),
+            trait_name: None,
+        },
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Default)]
+struct Shaking {
+    /// The identifiers of the solution and of the kept items.
+    referenced: BTreeSet<String>,
+    /// The names of the kept items and modules.
+    included: BTreeSet<String>,
+    /// The names of all modules of the library.
+    modules: BTreeSet<String>,
+}
+
+impl Shaking {
+    /// Imports are kept if they import something that is used, or everything of a module that is kept.
+    fn keeps_import(&self, item: &Item) -> bool {
+        let dropped_module = item
+            .idents
+            .iter()
+            .any(|i| self.modules.contains(i) && !self.included.contains(i));
+        let glob = item.text.contains('*');
+
+        !dropped_module && (glob || item.idents.iter().any(|i| self.referenced.contains(i)))
+    }
+}
+
+/// Removes the items of a module that the referenced identifiers do not lead to. Returns whether anything is left.
+fn shake(module: &Module, state: &mut Shaking) -> (Module, bool) {
+    let mut items = vec![];
+    let mut used = false;
+
+    for item in module.items.iter().filter(|item| !item.is_test) {
+        if let Some(sub) = &item.module {
+            if item.name.as_deref() == Some(TEMPLATE_MODULE) {
+                continue;
+            }
+            let (sub, sub_used) = shake(sub, state);
+            if sub_used {
+                used = true;
+                state.included.extend(item.name.clone());
+                items.push(Item {
+                    module: Some(sub),
+                    ..item.clone()
+                });
+            }
+            continue;
+        }
+
+        let keep = match &item.kind {
+            Kind::Impl {
+                self_type,
+                trait_name,
+            } => [self_type, trait_name]
+                .iter()
+                .any(|n| n.as_ref().is_some_and(|n| state.included.contains(n))),
+            Kind::Fn | Kind::Type | Kind::Const => item
+                .name
+                .as_ref()
+                .is_some_and(|n| state.referenced.contains(n)),
+            // imports and attributes are kept along with the module, they do not make it used.
+            Kind::Use => {
+                if state.keeps_import(item) {
+                    items.push(item.clone());
+                }
+                continue;
+            }
+            Kind::InnerAttribute | Kind::MacroCall(_) | Kind::Other | Kind::Mod => {
+                items.push(item.clone());
+                continue;
+            }
+        };
+
+        if keep {
+            used = true;
+            state.referenced.extend(item.idents.iter().cloned());
+            state.included.extend(item.name.clone());
+            items.push(item.clone());
+        }
+    }
+
+    (
+        Module {
+            header: module.header.clone(),
+            items,
+        },
+        used,
+    )
+}
+
+/// Keeps the items of the library that the seed identifiers lead to, including through other helpers.
+fn shake_library(library: &Module, seeds: &BTreeSet<String>) -> Module {
+    let mut state = Shaking {
+        referenced: seeds.clone(),
+        ..Shaking::default()
+    };
+    collect_modules(&library.items, &mut state.modules);
+
+    // kept items can lead to items that were already passed, so repeat until nothing is added.
+    loop {
+        let before = (state.referenced.len(), state.included.len());
+        let (shaken, _) = shake(library, &mut state);
+        if before == (state.referenced.len(), state.included.len()) {
+            return shaken;
+        }
+    }
+}
+
+fn collect_modules(items: &[Item], modules: &mut BTreeSet<String>) {
+    for item in items {
+        if let (Some(module), Some(name)) = (&item.module, &item.name) {
+            modules.insert(name.clone());
+            collect_modules(&module.items, modules);
+        }
+    }
+}
+
+/// Renders the items of a module, indenting nested modules. Blank lines between items are kept.
+fn render_items(items: &[Item], out: &mut String) {
+    for item in items.iter().filter(|item| !item.text.trim().is_empty()) {
+        let text = item.text.trim_start();
+        let leading = &item.text[..item.text.len() - text.len()];
+        if leading.matches('\n').count() > 1 && !out.is_empty() {
+            out.push('\n');
+        }
+
+        match &item.module {
+            Some(module) => {
+                let _ = writeln!(out, "{} {{", module.header);
+                let mut body = String::new();
+                render_items(&module.items, &mut body);
+                out.push_str(&indent(body.trim_matches('\n')));
+                out.push_str("\n}\n");
+            }
+            None => {
+                out.push_str(text);
+                out.push('\n');
+            }
+        }
+    }
+}
+
+/// Indents every line that does not start inside a string literal.
+fn indent(text: &str) -> String {
+    let literals: Vec<(usize, usize)> = tokenize(text)
+        .iter()
+        .filter(|t| t.kind == TokenKind::Literal)
+        .map(|t| (t.start, t.end))
+        .collect();
+
+    let mut out = String::new();
+    let mut start = 0;
+    for line in text.split('\n') {
+        let in_literal = literals.iter().any(|(s, e)| *s < start && start < *e);
+        if !in_literal && !line.trim().is_empty() {
+            out.push_str("    ");
+        }
+        out.push_str(line);
+        out.push('\n');
+        start += line.len() + 1;
+    }
+    out.pop();
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// What the header of a bundle says about the day.
+#[derive(Clone, Debug, Default)]
+pub struct Header {
+    /// E.g. `Advent of Code 2018, day 13: Mine Cart Madness`.
+    pub title: String,
+    pub url: Option<String>,
+    /// The path of the solution file.
+    pub source: String,
+}
+
+/// A solution bundled into a single file.
+#[derive(Clone, Debug)]
+pub struct Bundle {
+    pub code: String,
+    /// Names of the inlined helper items.
+    pub helpers: Vec<String>,
+    /// The dependencies from `Cargo.toml` that the code uses, e.g. `num = "0.4.1"`.
+    pub dependencies: Vec<String>,
+}
+
+/// Bundles a solution with the helpers of the library. `lib_path` is the path of `src/lib.rs`.
+/// `cargo_toml` is used to list the crates that the bundle depends on.
+pub fn bundle(
+    solution: &str,
+    lib_path: &Path,
+    header: &Header,
+    cargo_toml: &str,
+    loader: Loader,
+) -> Result<Bundle, String> {
+    let src_dir = lib_path
+        .parent()
+        .map_or_else(PathBuf::new, Path::to_path_buf);
+    let library = Module {
+        header: String::new(),
+        items: match loader(lib_path) {
+            Some(source) => parse(&source, &src_dir, loader)?,
+            None => vec![],
+        },
+    };
+
+    let mut items: Vec<Item> = parse(solution, Path::new(""), loader)?
+        .into_iter()
+        .filter(|item| !item.is_test)
+        .collect();
+
+    let mut main = None;
+    for item in &mut items {
+        if matches!(&item.kind, Kind::MacroCall(path) if *path == format!("{CRATE_NAME}::solution"))
+        {
+            main = Some(generate_main(&item.text)?);
+            item.text = String::new();
+        } else {
+            item.text = rewrite_crate_paths(&item.text)?;
+        }
+    }
+    let main = main.ok_or("the solution does not call `solution!`")?;
+
+    if items
+        .iter()
+        .any(|item| item.name.as_deref() == Some("main"))
+    {
+        return Err("the solution defines its own `main`".into());
+    }
+
+    let seeds: BTreeSet<String> = items
+        .iter()
+        .flat_map(|item| item.idents.iter().cloned())
+        .collect();
+    let library = shake_library(&library, &seeds);
+
+    let (inner, items): (Vec<Item>, Vec<Item>) = items
+        .into_iter()
+        .partition(|item| item.kind == Kind::InnerAttribute);
+
+    let mut code = String::new();
+    render_items(&inner, &mut code);
+    render_items(&items, &mut code);
+    code.push('\n');
+    code.push_str(&main);
+
+    let mut helpers = vec![];
+    collect_names(&library.items, &mut helpers);
+    if !helpers.is_empty() {
+        let _ = writeln!(
+            code,
+            "\n/* ---- helpers from {} ---- */\n",
+            lib_path.display()
+        );
+        render_items(&library.items, &mut code);
+    }
+
+    if ["include_str", "include_bytes"]
+        .iter()
+        .any(|m| tokenize(&code).iter().any(|t| t.is(m)))
+    {
+        return Err("the solution embeds a file, which is not bundled".into());
+    }
+
+    let used: BTreeSet<&str> = tokenize(&code)
+        .iter()
+        .filter(|t| t.kind == TokenKind::Ident)
+        .map(|t| t.text)
+        .collect();
+    let dependencies: Vec<String> = dependencies(cargo_toml)
+        .into_iter()
+        .filter(|(name, _)| used.contains(name.replace('-', "_").as_str()))
+        .map(|(name, spec)| format!("{name} = {spec}"))
+        .collect();
+
+    Ok(Bundle {
+        code: format!(
+            "{}\n{}",
+            render_header(header, &dependencies),
+            code.trim_start()
+        ),
+        helpers,
+        dependencies,
+    })
+}
+
+/// Replaces `advent_of_code::` with `crate::`, as the helpers are inlined at the root of the bundle.
+fn rewrite_crate_paths(text: &str) -> Result<String, String> {
+    let tokens = tokenize(text);
+    let mut out = String::new();
+    let mut last = 0;
+
+    for (i, token) in tokens.iter().enumerate() {
+        if token.is(CRATE_NAME) && tokens.get(i + 1).is_some_and(|t| t.is(":")) {
+            if tokens.get(i + 3).is_some_and(|t| t.is(TEMPLATE_MODULE)) {
+                return Err(format!(
+                    "the solution uses `{CRATE_NAME}::{TEMPLATE_MODULE}`, which is not bundled"
+                ));
+            }
+            out.push_str(&text[last..token.start]);
+            out.push_str("crate");
+            last = token.end;
+        }
+    }
+
+    out.push_str(&text[last..]);
+    Ok(out)
+}
+
+/// Replaces the `solution!` macro with a `main` that reads the input from stdin.
+fn generate_main(call: &str) -> Result<String, String> {
+    let tokens = tokenize(call);
+    let args: Vec<&str> = tokens
+        .iter()
+        .skip_while(|t| !t.is("("))
+        .filter(|t| t.kind == TokenKind::Literal)
+        .map(|t| t.text)
+        .collect();
+
+    let parts: Vec<(&str, u8)> = match args.get(1).copied() {
+        None => vec![("part_one", 1), ("part_two", 2)],
+        Some("1") => vec![("part_one", 1)],
+        Some("2") => vec![("part_two", 2)],
+        Some(part) => return Err(format!("unexpected part `{part}` in `solution!`")),
+    };
+
+    let mut main = String::from(
+        "fn main() {\n    let mut input = String::new();\n    std::io::Read::read_to_string(&mut std::io::stdin(), &mut input)\n        .expect(\"could not read the input from stdin\");\n",
+    );
+    for (func, part) in parts {
+        let _ = write!(
+            main,
+            "\n    match {func}(&input) {{\n        Some(result) => println!(\"Part {part}: {{result}}\"),\n        None => println!(\"Part {part}: ✖\"),\n    }}\n"
+        );
+    }
+    main.push_str("}\n");
+    Ok(main)
+}
+
+fn collect_names(items: &[Item], names: &mut Vec<String>) {
+    for item in items {
+        match (&item.module, &item.name) {
+            (Some(module), _) => collect_names(&module.items, names),
+            (None, Some(name)) => names.push(name.clone()),
+            (None, None) => {}
+        }
+    }
+}
+
+/// Reads the `[dependencies]` section of a manifest as pairs of name and version or table.
+fn dependencies(cargo_toml: &str) -> Vec<(String, String)> {
+    let mut in_section = false;
+    let mut dependencies = vec![];
+
+    for line in cargo_toml.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_section = line == "[dependencies]";
+        } else if let (true, Some((name, spec))) = (in_section, line.split_once('=')) {
+            if !name.trim().starts_with('#') {
+                dependencies.push((name.trim().to_string(), spec.trim().to_string()));
+            }
+        }
+    }
+
+    dependencies
+}
+
+fn render_header(header: &Header, dependencies: &[String]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "//! {}", header.title);
+    if let Some(url) = &header.url {
+        let _ = writeln!(out, "//! {url}");
+    }
+    let _ = writeln!(out, "//!");
+    let _ = writeln!(
+        out,
+        "//! Bundled from `{}`. Pass the puzzle input on stdin, e.g.:",
+        header.source
+    );
+
+    if dependencies.is_empty() {
+        let name = Path::new(&header.source)
+            .file_stem()
+            .map_or_else(|| "main".into(), |s| s.to_string_lossy().to_string());
+        let _ = writeln!(out, "//! `rustc -O {name}.rs && ./{name} < input.txt`");
+    } else {
+        let _ = writeln!(out, "//! `cargo run --release < input.txt`");
+        let _ = writeln!(out, "//!");
+        let _ = writeln!(out, "//! Dependencies:");
+        for dependency in dependencies {
+            let _ = writeln!(out, "//! {dependency}");
+        }
+    }
+
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::Path;
+
+    use super::{bundle, dependencies, tokenize, Header, TokenKind};
+
+    const LIB: &str = r#"pub mod template;
+pub mod grid;
+
+/// Parses all numbers of a line.
+pub fn numbers(line: &str) -> Vec<i64> {
+    line.split(|c: char| !c.is_ascii_digit() && c != '-')
+        .filter_map(|n| n.parse().ok())
+        .collect()
+}
+
+pub fn unused() -> &'static str {
+    "{ not a block"
+}
+"#;
+
+    const GRID: &str = r#"use std::fmt::Display;
+
+pub const NEIGHBOURS: [(i32, i32); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+pub struct Grid {
+    pub cells: Vec<Vec<char>>,
+}
+
+impl Grid {
+    pub fn parse(input: &str) -> Self {
+        Self { cells: input.lines().map(|l| l.chars().collect()).collect() }
+    }
+
+    pub fn neighbours(&self) -> usize {
+        NEIGHBOURS.len()
+    }
+}
+
+impl Display for Grid {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, r"{}", self.cells.len())
+    }
+}
+
+pub struct Unused;
+
+impl Unused {
+    fn text() -> String {
+        String::from("
+indented")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn parses() {}
+}
+"#;
+
+    const SOLUTION: &str = r#"advent_of_code::solution!(7, 1);
+
+use advent_of_code::grid::Grid;
+use num::Integer;
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let grid = Grid::parse(input);
+    Some(grid.neighbours() + input.len().lcm(&2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, None);
+    }
+}
+"#;
+
+    const CARGO_TOML: &str = "[features]\ntoday = [\"chrono\"]\n\n[dependencies]\n# Template dependencies\nchrono = { version = \"0.4.31\", optional = true }\nnum = \"0.4.1\"\n";
+
+    fn load(path: &Path) -> Option<String> {
+        match path.to_str()? {
+            "src/lib.rs" => Some(LIB.into()),
+            "src/grid.rs" => Some(GRID.into()),
+            _ => None,
+        }
+    }
+
+    fn header() -> Header {
+        Header {
+            title: "Advent of Code 2018, day 07".into(),
+            url: None,
+            source: "src/bin/07.rs".into(),
+        }
+    }
+
+    #[test]
+    fn tokenizes_literals_and_lifetimes() {
+        let tokens = tokenize("fn a<'a>(s: &'a str) -> char { let _ = r#\"}\"#; '}' } // }");
+        let kinds: Vec<TokenKind> = tokens
+            .iter()
+            .filter(|t| t.kind != TokenKind::Punct)
+            .map(|t| t.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                TokenKind::Ident,
+                TokenKind::Ident,
+                TokenKind::Lifetime,
+                TokenKind::Ident,
+                TokenKind::Lifetime,
+                TokenKind::Ident,
+                TokenKind::Ident,
+                TokenKind::Ident,
+                TokenKind::Ident,
+                TokenKind::Literal,
+                TokenKind::Literal
+            ]
+        );
+    }
+
+    #[test]
+    fn inlines_used_helpers() {
+        let bundle = bundle(
+            SOLUTION,
+            Path::new("src/lib.rs"),
+            &header(),
+            CARGO_TOML,
+            &load,
+        )
+        .unwrap();
+
+        assert_eq!(bundle.helpers, vec!["NEIGHBOURS", "Grid"]);
+        assert_eq!(bundle.dependencies, vec!["num = \"0.4.1\""]);
+
+        let code = &bundle.code;
+        assert!(code.starts_with("//! Advent of Code 2018, day 07\n"));
+        assert!(code.contains("use crate::grid::Grid;"));
+        assert!(code.contains("\npub mod grid {\n    use std::fmt::Display;\n\n    pub const"));
+        assert!(code.contains("    impl Display for Grid {"));
+        assert!(code.contains("Some(result) => println!(\"Part 1: {result}\")"));
+        assert!(!code.contains("Part 2"));
+        assert!(!code.contains("fn numbers"));
+        assert!(!code.contains("Unused"));
+        assert!(!code.contains("advent_of_code"));
+        assert!(!code.contains("mod tests"));
+        assert!(!code.contains("mod template"));
+    }
+
+    #[test]
+    fn rejects_template_usage() {
+        let solution = "advent_of_code::solution!(7);\n\npub fn part_one(input: &str) -> Option<u32> {\n    advent_of_code::template::read_file(\"inputs\", DAY);\n    None\n}\n";
+        let result = bundle(solution, Path::new("src/lib.rs"), &header(), "", &load);
+        assert!(result.is_err_and(|e| e.contains("template")));
+    }
+
+    #[test]
+    fn reads_dependencies() {
+        assert_eq!(
+            dependencies(CARGO_TOML),
+            vec![
+                (
+                    "chrono".to_string(),
+                    "{ version = \"0.4.31\", optional = true }".to_string()
+                ),
+                ("num".to_string(), "\"0.4.1\"".to_string())
+            ]
+        );
+    }
+}
@@ -0,0 +1,52 @@
+/// Module that caches fetched pages in the data folder, e.g. private leaderboards and the stats page.
+use std::{
+    fs,
+    path::Path,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::error::Error;
+
+/// The site asks to not request private leaderboards more often than every 15 minutes,
+/// and the stats page changes slowly, so both are reused for as long.
+const CACHE_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// Returns the cached copy at `cache_path` if it is recent (or `cached` is set), calls `fetch` and caches the result otherwise.
+/// If fetching fails, an outdated copy is used instead. `what` names the page in messages, e.g. `leaderboard`.
+pub fn cached_fetch(
+    cache_path: &str,
+    cached: bool,
+    what: &str,
+    fetch: impl FnOnce() -> Result<String, Error>,
+) -> Result<String, Error> {
+    let cache_age = fs::metadata(cache_path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+
+    let read_cache = || {
+        fs::read_to_string(cache_path)
+            .map_err(|e| Error::io(format!("Failed to read cached {what} \"{cache_path}\""), e))
+    };
+
+    if cached || cache_age.is_some_and(|age| age < CACHE_TTL) {
+        return read_cache();
+    }
+
+    match fetch() {
+        Ok(body) => {
+            if let Some(dir) = Path::new(cache_path).parent() {
+                let _ = fs::create_dir_all(dir);
+            }
+            if let Err(e) = fs::write(cache_path, &body) {
+                eprintln!("Failed to cache the {what}: {e}");
+            }
+            Ok(body)
+        }
+        Err(e) if cache_age.is_some() => {
+            eprintln!("Failed to fetch the {what} ({e}), falling back to \"{cache_path}\".");
+            read_cache()
+        }
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,188 @@
+use std::{
+    fs,
+    io::{self, BufRead, IsTerminal, Write},
+    path::Path,
+};
+
+use crate::template::{
+    accounts::{self, Accounts},
+    aoc_cli, config,
+    error::Error,
+    http, ANSI_BOLD, ANSI_RESET,
+};
+
+pub enum AccountCommand {
+    /// Adds an account. The token is read from stdin if not passed.
+    Add {
+        name: String,
+        token: Option<String>,
+    },
+    List,
+    Select {
+        name: String,
+    },
+    /// Checks that the token of an account (or all accounts) is still logged in.
+    Validate {
+        name: Option<String>,
+    },
+}
+
+pub fn handle(command: AccountCommand) -> Result<(), Error> {
+    let Some(path) = config::get().accounts_file_path() else {
+        return Err(Error::Usage(
+            "Could not determine the home directory. Configure `accounts_file` in aoc.json.".into(),
+        ));
+    };
+
+    let mut accounts = Accounts::read_from_file(&path);
+
+    match command {
+        AccountCommand::Add { name, token } => {
+            if !accounts::is_valid_name(&name) {
+                return Err(Error::Usage(
+                    "Account names may only contain letters, digits, `-` and `_`.".into(),
+                ));
+            }
+
+            let token = token.unwrap_or_else(read_token);
+            if token.is_empty() {
+                return Err(Error::Usage("No session token given.".into()));
+            }
+
+            accounts.upsert(&name, &token);
+            let first = accounts.selected.is_none();
+            if first {
+                accounts.selected = Some(name.clone());
+            }
+            store(&accounts, &path)?;
+            create_account_folders(&name);
+
+            println!("🎄 Added account \"{name}\" to \"{}\".", path.display());
+            if first {
+                print_selected(&accounts);
+            } else {
+                println!("🎄 Type `cargo account select {name}` to act as this account.");
+            }
+        }
+        AccountCommand::List => {
+            if accounts.data.is_empty() {
+                println!("No accounts configured. Add one with `cargo account add <name>`.");
+                return Ok(());
+            }
+
+            let active = config::get().account.as_ref().map(|a| a.name.as_str());
+            for account in &accounts.data {
+                if active == Some(account.name.as_str()) {
+                    println!("* {ANSI_BOLD}{}{ANSI_RESET}", account.name);
+                } else {
+                    println!("  {}", account.name);
+                }
+            }
+        }
+        AccountCommand::Select { name } => {
+            if accounts.get(&name).is_none() {
+                return Err(Error::Usage(format!(
+                    "Unknown account \"{name}\". Add it with `cargo account add {name}`."
+                )));
+            }
+
+            accounts.selected = Some(name.clone());
+            store(&accounts, &path)?;
+            create_account_folders(&name);
+            print_selected(&accounts);
+        }
+        AccountCommand::Validate { name } => {
+            let selected: Vec<_> = accounts
+                .data
+                .iter()
+                .filter(|a| name.as_ref().is_none_or(|name| a.name == *name))
+                .collect();
+
+            if selected.is_empty() {
+                return Err(Error::Usage("No matching accounts configured.".into()));
+            }
+
+            let mut failed = vec![];
+            for account in selected {
+                match validate(&account.token) {
+                    Ok(user) => println!("✔ {}: logged in as {user}", account.name),
+                    Err(e) => {
+                        failed.push(account.name.as_str());
+                        println!("✖ {}: {e}", account.name);
+                    }
+                }
+            }
+
+            if !failed.is_empty() {
+                return Err(Error::Failed(format!(
+                    "Failed to validate account(s) {}.",
+                    failed.join(", ")
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn store(accounts: &Accounts, path: &Path) -> Result<(), Error> {
+    accounts.store_file(path).map_err(|e| {
+        Error::io(
+            format!("Failed to store accounts in \"{}\"", path.display()),
+            e,
+        )
+    })
+}
+
+fn print_selected(accounts: &Accounts) {
+    if let Some(name) = &accounts.selected {
+        println!(
+            "🎄 Commands now act as account \"{name}\", inputs are read from \"{}\".",
+            config::get().account_inputs_folder(name)
+        );
+    }
+}
+
+/// Each account has its own inputs, puzzle descriptions, answers and stats, as they differ between accounts.
+fn create_account_folders(name: &str) {
+    for folder in config::get().account_folders(name) {
+        if let Err(e) = fs::create_dir_all(&folder) {
+            eprintln!("Failed to create directory \"{folder}\": {e}");
+        }
+    }
+}
+
+fn read_token() -> String {
+    if io::stdin().is_terminal() {
+        print!("Session token: ");
+        let _ = io::stdout().flush();
+    }
+
+    let mut token = String::new();
+    let _ = io::stdin().lock().read_line(&mut token);
+    token.trim().to_string()
+}
+
+/// Returns the user name shown on the site if the token is logged in.
+fn validate(token: &str) -> Result<String, String> {
+    let base_url = &config::get().base_url;
+    let url = match aoc_cli::get_year() {
+        Some(year) => format!("{base_url}/{year}"),
+        None => format!("{base_url}/"),
+    };
+
+    let page = http::get(&url, Some(token)).map_err(|e| e.to_string())?;
+
+    if !page.contains("/auth/logout") {
+        return Err("the session token is expired or invalid.".into());
+    }
+
+    let user = page
+        .split_once("<div class=\"user\">")
+        .and_then(|(_, rest)| rest.split('<').next())
+        .map(str::trim)
+        .filter(|user| !user.is_empty())
+        .unwrap_or("an anonymous user");
+
+    Ok(user.to_string())
+}
@@ -1,5 +1,13 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::collections::BTreeSet;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::{error::Error, run_multi::run_multi, Day};
+
+pub fn handle(days: &BTreeSet<Day>, is_release: bool) -> Result<(), Error> {
+    let run = run_multi(days, is_release, false)?;
+
+    if run.failed.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::Solution(run.failed))
+    }
 }
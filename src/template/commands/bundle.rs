@@ -0,0 +1,110 @@
+use std::{
+    collections::BTreeSet,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use crate::template::{
+    bundle::{self, Header},
+    config,
+    error::Error,
+    puzzle::Puzzle,
+    Day,
+};
+
+const LIB_PATH: &str = "src/lib.rs";
+
+/// Bundles the solution of each day into `target/bundle/DD.rs`, with the helpers it uses inlined.
+pub fn handle(days: &BTreeSet<Day>) -> Result<(), Error> {
+    let config = config::get();
+    let days: Vec<Day> = days
+        .iter()
+        .copied()
+        .filter(|day| Path::new(&config.bin_path(*day)).exists())
+        .collect();
+
+    if days.is_empty() {
+        return Err(Error::Usage(
+            "None of the selected days has a solution yet.".into(),
+        ));
+    }
+
+    let folder = bundle_folder();
+    fs::create_dir_all(&folder)
+        .map_err(|e| Error::io(format!("Failed to create \"{}\"", folder.display()), e))?;
+
+    let cargo_toml = fs::read_to_string("Cargo.toml").unwrap_or_default();
+    let mut failed = vec![];
+
+    for day in days {
+        let source_path = config.bin_path(day);
+        let solution = fs::read_to_string(&source_path)
+            .map_err(|e| Error::io(format!("Failed to read \"{source_path}\""), e))?;
+
+        let result = bundle::bundle(
+            &solution,
+            Path::new(LIB_PATH),
+            &header(day, &source_path),
+            &cargo_toml,
+            &|path: &Path| fs::read_to_string(path).ok(),
+        );
+
+        match result {
+            Ok(bundle) => {
+                let path = folder.join(format!("{day}.rs"));
+                fs::write(&path, &bundle.code)
+                    .map_err(|e| Error::io(format!("Failed to write \"{}\"", path.display()), e))?;
+
+                let helpers = match bundle.helpers.len() {
+                    0 => "no helpers".to_string(),
+                    _ => format!("helpers {}", bundle.helpers.join(", ")),
+                };
+                println!(
+                    "🎄 Bundled day {day} to \"{}\" with {helpers}.",
+                    path.display()
+                );
+                if !bundle.dependencies.is_empty() {
+                    println!("   Depends on {}.", bundle.dependencies.join(", "));
+                }
+            }
+            Err(e) => {
+                eprintln!("Failed to bundle day {day}: {e}.");
+                failed.push(day.to_string());
+            }
+        }
+    }
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::Failed(format!(
+            "Could not bundle day(s) {}.",
+            failed.join(", ")
+        )))
+    }
+}
+
+/// Bundles are build output, so they go into the target folder and are never committed by accident.
+fn bundle_folder() -> PathBuf {
+    let target = env::var("CARGO_TARGET_DIR").unwrap_or_else(|_| "target".into());
+    PathBuf::from(target).join("bundle")
+}
+
+fn header(day: Day, source_path: &str) -> Header {
+    let config = config::get();
+    let event = match config.year {
+        Some(year) => format!("Advent of Code {year}, day {}", day.into_inner()),
+        None => format!("Advent of Code, day {}", day.into_inner()),
+    };
+
+    Header {
+        title: match Puzzle::read(day).ok().and_then(|p| p.title()) {
+            Some(title) => format!("{event}: {title}"),
+            None => event,
+        },
+        url: config
+            .year
+            .map(|year| format!("{}/{year}/day/{}", config.base_url, day.into_inner())),
+        source: source_path.to_string(),
+    }
+}
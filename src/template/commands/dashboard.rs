@@ -0,0 +1,305 @@
+use std::{
+    env,
+    io::{self, BufRead, BufReader, IsTerminal, Read, Write},
+    process::{Child, Command, Stdio},
+    sync::mpsc::{self, RecvTimeoutError, Sender},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::template::{
+    all_days,
+    answers::Answers,
+    dashboard::{parse_keys, Action, Dashboard, Key},
+    error::Error,
+    markdown,
+    puzzle::Puzzle,
+    stats::Stats,
+    status::{DayStatus, PartStatus},
+    timings::Timings,
+    Day,
+};
+
+/// How long to wait for input before checking on the running command.
+const TICK: Duration = Duration::from_millis(100);
+/// The terminal size is polled, as there is no portable way to be notified of a resize.
+const RESIZE_INTERVAL: Duration = Duration::from_secs(1);
+
+enum Event {
+    Key(Key),
+    /// A line of output of the run with the given id.
+    Output(usize, String),
+}
+
+/// Puts the terminal into raw mode on the alternate screen, and restores it when dropped.
+struct Terminal {
+    saved: String,
+}
+
+impl Terminal {
+    fn enter() -> Result<Self, Error> {
+        let saved = stty(&["-g"])?;
+        stty(&["raw", "-echo"])?;
+        // alternate screen, hidden cursor.
+        print!("\x1b[?1049h\x1b[?25l");
+        let _ = io::stdout().flush();
+        Ok(Self {
+            saved: saved.trim().to_string(),
+        })
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        print!("\x1b[?25h\x1b[?1049l");
+        let _ = io::stdout().flush();
+        let _ = stty(&[&self.saved]);
+    }
+}
+
+fn stty(args: &[&str]) -> Result<String, Error> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .output()
+        .map_err(|e| Error::io("Failed to call stty", e))?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        Err(Error::Failed(format!(
+            "stty failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )))
+    }
+}
+
+/// Returns the number of columns and rows of the terminal.
+fn terminal_size() -> (usize, usize) {
+    stty(&["size"])
+        .ok()
+        .and_then(|size| {
+            let (rows, cols) = size.trim().split_once(' ')?;
+            Some((cols.parse().ok()?, rows.parse().ok()?))
+        })
+        .filter(|(cols, rows)| *cols > 0 && *rows > 0)
+        .unwrap_or_else(|| (markdown::terminal_width(), 24))
+}
+
+pub fn handle() -> Result<(), Error> {
+    if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+        return Err(Error::Usage(
+            "The dashboard needs an interactive terminal.".into(),
+        ));
+    }
+
+    let statuses = collect_statuses();
+    // start at the first day that is not solved yet.
+    let selected = statuses
+        .iter()
+        .find(|s| s.parts != [PartStatus::Solved; 2])
+        .map_or_else(|| crate::day!(1), |s| s.day);
+    let mut dashboard = Dashboard::new(selected, statuses, collect_titles());
+
+    let terminal = Terminal::enter()?;
+    let (tx, rx) = mpsc::channel();
+    read_keys(tx.clone());
+
+    let mut running: Option<Child> = None;
+    let mut run_id = 0;
+    let mut size = terminal_size();
+    let mut last_resize = Instant::now();
+    let mut dirty = true;
+
+    loop {
+        if dirty {
+            print!("{}", dashboard.render(size.0, size.1));
+            let _ = io::stdout().flush();
+            dirty = false;
+        }
+
+        match rx.recv_timeout(TICK) {
+            Ok(Event::Key(key)) => {
+                dirty = true;
+
+                if let Some(action) = dashboard.confirm.take() {
+                    if key == Key::Char('y') {
+                        running = start(&mut dashboard, action, &tx, &mut run_id, size.0);
+                    }
+                    continue;
+                }
+
+                match key {
+                    Key::Char('q') => break,
+                    Key::Char('x') => {
+                        if let Some(mut child) = running.take() {
+                            stop(&mut child);
+                            dashboard.running = None;
+                            dashboard.message = "cancelled".into();
+                        }
+                    }
+                    Key::Up | Key::Down | Key::Left | Key::Right => {
+                        dashboard.move_selection(key);
+                    }
+                    key => match Action::from_key(key) {
+                        Some(_) if running.is_some() => {
+                            dashboard.message = "a command is running, press x to cancel it".into();
+                        }
+                        Some(action @ Action::Submit(_)) => dashboard.confirm = Some(action),
+                        Some(action) => {
+                            running = start(&mut dashboard, action, &tx, &mut run_id, size.0);
+                        }
+                        None => {}
+                    },
+                }
+            }
+            Ok(Event::Output(id, line)) => {
+                // output of a cancelled run may still arrive.
+                if id == run_id {
+                    dashboard.push_output(&line);
+                    dirty = true;
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+
+        if let Some(child) = &mut running {
+            if let Ok(Some(status)) = child.try_wait() {
+                let label = dashboard
+                    .running
+                    .take()
+                    .map(|(action, day)| format!("{} day {day}", action.label()))
+                    .unwrap_or_default();
+                dashboard.message = match status.code() {
+                    Some(0) => format!("{label}: done"),
+                    Some(code) => format!("{label}: failed with exit code {code}"),
+                    None => format!("{label}: stopped"),
+                };
+                dashboard.statuses = collect_statuses();
+                dashboard.titles = collect_titles();
+                running = None;
+                dirty = true;
+            }
+        }
+
+        if last_resize.elapsed() >= RESIZE_INTERVAL {
+            let current = terminal_size();
+            dirty |= current != size;
+            size = current;
+            last_resize = Instant::now();
+        }
+    }
+
+    if let Some(mut child) = running {
+        stop(&mut child);
+    }
+    drop(terminal);
+    Ok(())
+}
+
+/// Runs the subcommand of an action with this binary, streaming its output into the dashboard.
+fn start(
+    dashboard: &mut Dashboard,
+    action: Action,
+    tx: &Sender<Event>,
+    run_id: &mut usize,
+    width: usize,
+) -> Option<Child> {
+    let day = dashboard.selected;
+    *run_id += 1;
+    dashboard.output.clear();
+    dashboard.message.clear();
+
+    let child = env::current_exe().and_then(|exe| {
+        let mut command = Command::new(exe);
+        // a group of its own, so that the `cargo` it runs and the solution can be stopped with it.
+        #[cfg(unix)]
+        std::os::unix::process::CommandExt::process_group(&mut command, 0);
+
+        command
+            .args(action.args(day))
+            .env("COLUMNS", width.to_string())
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+    });
+
+    match child {
+        Ok(mut child) => {
+            if let Some(stdout) = child.stdout.take() {
+                forward_lines(stdout, *run_id, tx.clone());
+            }
+            if let Some(stderr) = child.stderr.take() {
+                forward_lines(stderr, *run_id, tx.clone());
+            }
+            dashboard.running = Some((action, day));
+            Some(child)
+        }
+        Err(e) => {
+            dashboard.message = format!("failed to {}: {e}", action.label());
+            None
+        }
+    }
+}
+
+/// Stops a run together with the processes it started, e.g. a benchmark or a submission
+/// that should not go through after it was cancelled. Falls back to stopping the run only.
+fn stop(child: &mut Child) {
+    #[cfg(unix)]
+    let stopped = Command::new("kill")
+        .args(["-KILL", "--", &format!("-{}", child.id())])
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success());
+    #[cfg(not(unix))]
+    let stopped = false;
+
+    if !stopped {
+        let _ = child.kill();
+    }
+    let _ = child.wait();
+}
+
+fn forward_lines(stream: impl Read + Send + 'static, id: usize, tx: Sender<Event>) {
+    thread::spawn(move || {
+        for line in BufReader::new(stream).lines().map_while(Result::ok) {
+            if tx.send(Event::Output(id, line)).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+fn read_keys(tx: Sender<Event>) {
+    thread::spawn(move || {
+        let mut stdin = io::stdin();
+        let mut buf = [0u8; 32];
+        while let Ok(n) = stdin.read(&mut buf) {
+            if n == 0 {
+                break;
+            }
+            for key in parse_keys(&buf[..n]) {
+                if tx.send(Event::Key(key)).is_err() {
+                    return;
+                }
+            }
+        }
+    });
+}
+
+fn collect_statuses() -> Vec<DayStatus> {
+    let answers = Answers::read_from_file();
+    let stats = Stats::read_from_file();
+    let timings = Timings::read_from_file();
+    all_days()
+        .map(|day| DayStatus::collect(day, &answers, &stats, &timings))
+        .collect()
+}
+
+fn collect_titles() -> Vec<(Day, String)> {
+    all_days()
+        .filter_map(|day| Some((day, Puzzle::read(day).ok()?.title()?)))
+        .collect()
+}
@@ -0,0 +1,55 @@
+use std::fs;
+
+use crate::template::{
+    aoc_cli, cache, config,
+    error::Error,
+    event_stats::{self, EventStats},
+    http,
+    stats::Stats,
+    timings::Timings,
+};
+
+pub struct DifficultyOptions {
+    /// Read the stats page from this html file instead of fetching it.
+    pub file: Option<String>,
+    /// Only use the cached stats page, never fetch.
+    pub cached: bool,
+}
+
+pub fn handle(options: &DifficultyOptions) -> Result<(), Error> {
+    let Some(year) = aoc_cli::get_year() else {
+        return Err(Error::Usage("No year configured in aoc.json.".into()));
+    };
+
+    let html = match &options.file {
+        Some(path) => fs::read_to_string(path)
+            .map_err(|e| Error::io(format!("Failed to read \"{path}\""), e))?,
+        None => load(year, options.cached)?,
+    };
+
+    let event = EventStats::try_from(html)
+        .map_err(|e| Error::Failed(format!("Failed to load the stats page: {e}")))?;
+
+    let timings = Timings::read_from_file();
+
+    println!("Event stats {year}");
+    println!();
+    print!(
+        "{}",
+        event_stats::render(&event, &Stats::read_from_file(), &timings)
+    );
+
+    Ok(())
+}
+
+fn get_cache_path(year: u16) -> String {
+    config::get().data_file(&format!("stats/{year}.html"))
+}
+
+/// Returns the cached stats page if it is recent (or `cached` is set), fetches and caches it otherwise.
+fn load(year: u16, cached: bool) -> Result<String, Error> {
+    cache::cached_fetch(&get_cache_path(year), cached, "stats page", || {
+        let url = format!("{}/{year}/stats", config::get().base_url);
+        Ok(http::get(&url, None)?)
+    })
+}
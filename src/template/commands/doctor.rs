@@ -0,0 +1,269 @@
+use std::{
+    env, fs,
+    io::{stdout, Write},
+    path::Path,
+    process::{Command, Output, Stdio},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::template::{
+    all_days, aoc_cli,
+    config::{self, Config, CONFIG_FILE_PATH},
+    doctor::{self, Check, Severity},
+    error::Error,
+    module_template::ModuleTemplate,
+    ANSI_ITALIC, ANSI_RESET,
+};
+
+/// Features that are only built on request, so breakage goes unnoticed until they are needed.
+const FEATURES: [&str; 2] = ["dhat-heap", "today"];
+
+/// Checks the environment of the template and prints a fix for every problem.
+/// Fails if any problem was found, warnings only limit single commands.
+pub fn handle() -> Result<(), Error> {
+    let mut checks = vec![
+        check_tool(
+            "aoc-cli",
+            "aoc",
+            "-V",
+            "run `cargo install aoc-cli --version 0.12.0`",
+        ),
+        check_tool(
+            "curl",
+            "curl",
+            "--version",
+            "install curl with your package manager",
+        ),
+        check_stty(),
+    ];
+
+    for feature in FEATURES {
+        print!("\r{ANSI_ITALIC}Building the `{feature}` feature...{ANSI_RESET}");
+        let _ = stdout().flush();
+        checks.push(check_feature(feature));
+    }
+    print!("\r\x1b[2K");
+
+    checks.push(check_config_file());
+
+    // the remaining checks read the settings, which are unknown while aoc.json does not parse.
+    if let Ok(config) = config::load() {
+        checks.push(doctor::check_year(
+            env::var("AOC_YEAR").ok().as_deref(),
+            config.year,
+            current_year(),
+        ));
+        checks.push(check_session());
+        if let Some(check) = check_template() {
+            checks.push(check);
+        }
+
+        checks.extend(check_folders());
+
+        checks.push(doctor::check_readme(
+            &config.paths.readme,
+            fs::read_to_string(&config.paths.readme).ok().as_deref(),
+        ));
+
+        for day in all_days().filter(|day| Path::new(&config.bin_path(*day)).exists()) {
+            checks.extend(doctor::check_day_files(
+                day,
+                fs::read_to_string(config.input_path(day)).ok().as_deref(),
+                fs::read_to_string(config.example_path(day)).ok().as_deref(),
+            ));
+        }
+    }
+
+    print!("{}", doctor::render(&checks));
+
+    let count = |severity| checks.iter().filter(|c| c.severity == severity).count();
+    let (problems, warnings) = (count(Severity::Problem), count(Severity::Warning));
+    println!();
+
+    if problems == 0 {
+        println!("🎄 No problems found, {warnings} warning(s).");
+        Ok(())
+    } else {
+        Err(Error::Failed(format!(
+            "{problems} problem(s) and {warnings} warning(s) found."
+        )))
+    }
+}
+
+/// Calls a tool with an argument that prints its version.
+fn check_tool(name: &str, command: &str, version_arg: &str, fix: &str) -> Check {
+    match Command::new(command).arg(version_arg).output() {
+        Ok(output) if output.status.success() => {
+            // e.g. `curl 8.5.0 (x86_64-pc-linux-gnu) libcurl/8.5.0 ...`, keep the name and version.
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let version: Vec<&str> = stdout.split_whitespace().take(2).collect();
+            Check::ok("Tools", name, version.join(" "))
+        }
+        Ok(output) => Check::problem(
+            "Tools",
+            name,
+            format!("`{command} {version_arg}` failed: {}", failure(&output)),
+            fix,
+        ),
+        Err(_) => Check::problem("Tools", name, format!("`{command}` not found"), fix),
+    }
+}
+
+/// Calls stty the way `cargo dashboard` does first, which also fails if the input is not a terminal.
+fn check_stty() -> Check {
+    match Command::new("stty")
+        .arg("-g")
+        .stdin(Stdio::inherit())
+        .output()
+    {
+        Ok(output) if output.status.success() => Check::ok("Tools", "stty", "found"),
+        Ok(output) => Check::warning(
+            "Tools",
+            "stty",
+            format!(
+                "`stty -g` failed: {}, `cargo dashboard` needs it",
+                failure(&output)
+            ),
+            "run `cargo dashboard` from a terminal",
+        ),
+        Err(_) => Check::warning(
+            "Tools",
+            "stty",
+            "not found, `cargo dashboard` needs it",
+            "use a Unix terminal to run the dashboard",
+        ),
+    }
+}
+
+/// The error output of a failed command, or its exit status if it printed nothing.
+fn failure(output: &Output) -> String {
+    let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+    if stderr.is_empty() {
+        output.status.to_string()
+    } else {
+        stderr
+    }
+}
+
+fn check_feature(feature: &str) -> Check {
+    let name = format!("feature `{feature}`");
+    let output = Command::new("cargo")
+        .args(["check", "--quiet", "--lib", "--bin", "advent_of_code"])
+        .args(["--features", feature])
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .output();
+
+    match output {
+        Ok(output) if output.status.success() => Check::ok("Tools", name, "builds"),
+        Ok(output) => {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let error = stderr
+                .lines()
+                .find(|l| l.starts_with("error"))
+                .unwrap_or("failed to build");
+            Check::problem(
+                "Tools",
+                name,
+                error.to_string(),
+                format!("run `cargo check --features {feature}` to see all errors"),
+            )
+        }
+        Err(e) => Check::problem(
+            "Tools",
+            name,
+            format!("could not run cargo: {e}"),
+            "make sure cargo is on your PATH",
+        ),
+    }
+}
+
+/// All commands but `doctor` refuse to run while `aoc.json` does not parse.
+fn check_config_file() -> Check {
+    match fs::read_to_string(CONFIG_FILE_PATH) {
+        Err(_) => Check::ok(
+            "Configuration",
+            CONFIG_FILE_PATH,
+            "not present, using defaults",
+        ),
+        Ok(s) => match Config::try_from(s) {
+            Ok(_) => Check::ok("Configuration", CONFIG_FILE_PATH, "valid"),
+            Err(e) => Check::problem(
+                "Configuration",
+                CONFIG_FILE_PATH,
+                e,
+                "fix the file, until then all other commands fail",
+            ),
+        },
+    }
+}
+
+/// The template from `defaults.template`, if one is configured.
+fn check_template() -> Option<Check> {
+    let name = config::get().defaults.template.as_deref()?;
+
+    Some(match ModuleTemplate::load(name) {
+        Ok(_) => Check::ok("Configuration", "template", format!("\"{name}\"")),
+        Err(e) => Check::problem(
+            "Configuration",
+            "template",
+            e,
+            "add the template to the templates folder or change `defaults.template`",
+        ),
+    })
+}
+
+fn check_session() -> Check {
+    let config = config::get();
+
+    if let Some(name) = &config.unknown_account {
+        return Check::problem(
+            "Configuration",
+            "session",
+            format!("the selected account \"{name}\" is unknown"),
+            format!("run `cargo account add {name}`, select another account or unset AOC_ACCOUNT"),
+        );
+    }
+
+    if let Ok(Some(_)) = aoc_cli::session_token() {
+        return Check::ok("Configuration", "session", aoc_cli::session_description());
+    }
+
+    let path = config.session_file_path().map_or_else(
+        || "~/.adventofcode.session".into(),
+        |p| p.display().to_string(),
+    );
+    Check::problem(
+        "Configuration",
+        "session",
+        "no session token found, downloads and submissions will fail",
+        format!("paste your session cookie into \"{path}\", or run `cargo account add <name>`"),
+    )
+}
+
+fn check_folders() -> Vec<Check> {
+    ["inputs", "examples", "puzzles"]
+        .into_iter()
+        .map(|name| {
+            let folder = config::get().folder(name);
+            let path = folder.display().to_string();
+            if folder.is_dir() {
+                Check::ok("Data", path, "present")
+            } else {
+                Check::problem(
+                    "Data",
+                    &path,
+                    format!("{name} folder is missing"),
+                    format!("run `mkdir -p {path}`"),
+                )
+            }
+        })
+        .collect()
+}
+
+fn current_year() -> u16 {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    doctor::year_of(secs)
+}
@@ -1,14 +1,105 @@
-use crate::template::{aoc_cli, Day};
-use std::process;
+use crate::template::{answers, aoc_cli, error::Error, Day, ANSI_BOLD, ANSI_RESET};
+use std::{
+    fs, thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+/// Time to wait between two downloads of a bulk download, to not overload the site.
+const THROTTLE: Duration = Duration::from_secs(2);
+
+pub fn handle(day: Day) -> Result<(), Error> {
+    aoc_cli::check()?;
+    aoc_cli::download(day)?;
+    answers::harvest(day);
+    Ok(())
+}
+
+/// Downloads input and puzzle for several days, skipping days that are already present or not unlocked yet.
+pub fn handle_many(days: impl Iterator<Item = Day>) -> Result<(), Error> {
+    aoc_cli::check()?;
+
+    let mut downloaded = vec![];
+    let mut present = vec![];
+    let mut locked = vec![];
+    let mut failed = vec![];
+
+    for day in days {
+        if is_locked(day) {
+            locked.push(day);
+            continue;
+        }
+
+        if is_present(day) {
+            present.push(day);
+            continue;
+        }
+
+        if !downloaded.is_empty() || !failed.is_empty() {
+            thread::sleep(THROTTLE);
+        }
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        match aoc_cli::download(day) {
+            Ok(_) => {
+                answers::harvest(day);
+                downloaded.push(day);
+            }
+            Err(e) => {
+                eprintln!("failed to call aoc-cli: {e}");
+                failed.push(day);
+            }
+        }
+        println!();
+    }
+
+    let format_days = |days: &[Day]| -> String {
+        if days.is_empty() {
+            "-".into()
+        } else {
+            days.iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        }
+    };
+
+    println!(
+        "{ANSI_BOLD}Downloaded:{ANSI_RESET} {}",
+        format_days(&downloaded)
+    );
+    println!(
+        "{ANSI_BOLD}Already present:{ANSI_RESET} {}",
+        format_days(&present)
+    );
+    println!("{ANSI_BOLD}Locked:{ANSI_RESET} {}", format_days(&locked));
+    println!("{ANSI_BOLD}Failed:{ANSI_RESET} {}", format_days(&failed));
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::Failed(format!(
+            "Failed to download day(s) {}.",
+            format_days(&failed)
+        )))
     }
+}
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+/// Whether the puzzle of a day has not been unlocked yet. Unknown if no year is configured.
+fn is_locked(day: Day) -> bool {
+    let Some(year) = aoc_cli::get_year() else {
+        return false;
     };
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| i64::try_from(d.as_secs()).unwrap_or(i64::MAX));
+
+    now < day.unlock_timestamp(year)
+}
+
+/// Whether both a non-empty input and the puzzle description exist for a day.
+fn is_present(day: Day) -> bool {
+    let has_input = fs::metadata(aoc_cli::get_input_path(day)).is_ok_and(|m| m.len() > 0);
+    let has_puzzle = fs::metadata(aoc_cli::get_puzzle_path(day)).is_ok();
+    has_input && has_puzzle
 }
@@ -0,0 +1,114 @@
+use std::{
+    fs,
+    io::{self, BufRead, IsTerminal, Write},
+    path::Path,
+};
+
+use crate::template::{
+    config,
+    error::Error,
+    example_manifest::{example_file_names, ExampleEntry, ExampleManifest},
+    puzzle::{AnswerCandidates, Puzzle},
+    Day, ANSI_BOLD, ANSI_RESET,
+};
+
+pub fn handle(day: Day) -> Result<(), Error> {
+    let puzzle = Puzzle::read(day).map_err(|e| {
+        Error::io(
+            format!("Failed to read puzzle description (try running `cargo download {day}` first)"),
+            e,
+        )
+    })?;
+
+    extract(day, &puzzle, io::stdin().is_terminal())
+        .map_err(|e| Error::io("Failed to write examples", e))
+}
+
+/// Writes the code blocks of a puzzle to the examples folder and records the expected answers in the manifest.
+/// Ambiguous answers are only asked for if `interactive` is set, otherwise a previously picked answer is kept.
+pub fn extract(day: Day, puzzle: &Puzzle, interactive: bool) -> Result<(), io::Error> {
+    let blocks = puzzle.code_blocks();
+
+    if blocks.is_empty() {
+        println!("No code blocks found in the puzzle description.");
+    }
+
+    let config = config::get();
+    let parts: Vec<u8> = blocks.iter().map(|block| block.part).collect();
+    let files = example_file_names(day, &parts);
+
+    // examples may have been edited, e.g. to debug a solution, so they are never overwritten.
+    for (block, file_name) in blocks.iter().zip(&files) {
+        let path = format!("{}/{file_name}", config.paths.examples);
+        if Path::new(&path).exists() {
+            println!(
+                "Kept existing example for part {} in \"{path}\"",
+                block.part
+            );
+            continue;
+        }
+        fs::write(&path, &block.text)?;
+        println!("Wrote example for part {} to \"{path}\"", block.part);
+    }
+
+    // fill the default example file read by the scaffolded tests, but never overwrite it.
+    let default_path = config.example_path(day);
+    let default_is_empty = fs::read_to_string(&default_path).map_or(true, |s| s.trim().is_empty());
+    if let (true, Some(block)) = (default_is_empty, blocks.first()) {
+        fs::write(&default_path, &block.text)?;
+        println!("Wrote first example to \"{default_path}\"");
+    }
+
+    let mut answers = [None, None];
+    for (part, answer) in (1..).zip(answers.iter_mut()) {
+        if puzzle.part(part).is_none() {
+            continue;
+        }
+        *answer = choose_answer(part, &puzzle.answer_candidates(part), interactive);
+        match answer {
+            Some(value) => {
+                println!("Expected example answer for part {part}: {ANSI_BOLD}{value}{ANSI_RESET}")
+            }
+            None => println!("No example answer found for part {part}."),
+        }
+    }
+
+    let [part_1, part_2] = answers;
+    let mut manifest = ExampleManifest::read_from_file();
+    manifest.upsert(ExampleEntry {
+        day,
+        files,
+        part_1,
+        part_2,
+    });
+    manifest.store_file()
+}
+
+fn choose_answer(part: u8, candidates: &AnswerCandidates, interactive: bool) -> Option<String> {
+    if !candidates.is_ambiguous() {
+        return candidates.best.clone();
+    }
+
+    println!("Found several candidates for the example answer of part {part}:");
+    for (i, value) in candidates.values.iter().enumerate() {
+        println!("  #{} {value}", i + 1);
+    }
+
+    if !interactive {
+        println!("Skipping, run `cargo examples` in a terminal to pick one.");
+        return None;
+    }
+
+    loop {
+        print!("Pick a candidate like #1, type the answer or press enter to skip: ");
+        let _ = io::stdout().flush();
+
+        let mut line = String::new();
+        io::stdin().lock().read_line(&mut line).ok()?;
+
+        match candidates.choose(&line) {
+            Ok(answer) => return answer,
+            Err(e) => println!("{e}"),
+        }
+    }
+}
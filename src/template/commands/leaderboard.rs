@@ -0,0 +1,77 @@
+use std::fs;
+
+use crate::template::{
+    aoc_cli, cache, config,
+    error::Error,
+    http,
+    leaderboard::{self, Leaderboard},
+    Day,
+};
+
+pub struct LeaderboardOptions {
+    /// Read the leaderboard from this file instead of fetching it.
+    pub file: Option<String>,
+    /// Only use the cached leaderboard, never fetch.
+    pub cached: bool,
+    pub day: Option<Day>,
+    pub missing: bool,
+    pub times: bool,
+}
+
+pub fn handle(id: &str, options: &LeaderboardOptions) -> Result<(), Error> {
+    let json = match &options.file {
+        Some(path) => fs::read_to_string(path)
+            .map_err(|e| Error::io(format!("Failed to read \"{path}\""), e))?,
+        None => load(id, options.cached)?,
+    };
+
+    let leaderboard = Leaderboard::try_from(json)
+        .map_err(|e| Error::Failed(format!("Failed to load leaderboard: {e}")))?;
+
+    println!("Private leaderboard {id} ({})", leaderboard.event);
+    println!();
+
+    let day = options
+        .day
+        .or_else(|| options.missing.then(|| leaderboard.last_day()).flatten());
+
+    match day {
+        Some(day) => print!("{}", leaderboard::render_day(&leaderboard, day)),
+        None => print!("{}", leaderboard::render_overview(&leaderboard)),
+    }
+
+    if let (true, Some(day)) = (options.missing, day) {
+        println!();
+        print!("{}", leaderboard::render_missing(&leaderboard, day));
+    }
+
+    if options.times {
+        println!();
+        print!("{}", leaderboard::render_times(&leaderboard));
+    }
+
+    Ok(())
+}
+
+fn get_cache_path(id: &str) -> String {
+    config::get().data_file(&format!("leaderboards/{id}.json"))
+}
+
+/// Returns the cached leaderboard if it is recent (or `cached` is set), fetches and caches it otherwise.
+fn load(id: &str, cached: bool) -> Result<String, Error> {
+    cache::cached_fetch(&get_cache_path(id), cached, "leaderboard", || fetch(id))
+}
+
+fn fetch(id: &str) -> Result<String, Error> {
+    let year = aoc_cli::get_year()
+        .ok_or_else(|| Error::Usage("No year configured in aoc.json.".into()))?;
+    let session = aoc_cli::session_token()?.ok_or_else(|| {
+        Error::Usage("No session token found. Create the file ~/.adventofcode.session or configure `session_file` in aoc.json.".into())
+    })?;
+
+    let url = format!(
+        "{}/{year}/leaderboard/private/view/{id}.json",
+        config::get().base_url
+    );
+    Ok(http::get(&url, Some(&session))?)
+}
@@ -1,6 +1,20 @@
+pub mod account;
 pub mod all;
+pub mod bundle;
+pub mod dashboard;
+pub mod difficulty;
+pub mod doctor;
 pub mod download;
+pub mod examples;
+pub mod leaderboard;
+pub mod puzzles;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod stats;
+pub mod status;
+pub mod test_day;
 pub mod time;
+pub mod watch;
+#[cfg(feature = "today")]
+pub mod today;
@@ -0,0 +1,57 @@
+use crate::template::{
+    error::Error,
+    search::{self, Document},
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+
+pub enum PuzzlesCommand {
+    List,
+    Search { query: String },
+}
+
+pub fn handle(command: PuzzlesCommand) -> Result<(), Error> {
+    let documents = search::read_documents();
+
+    if documents.is_empty() {
+        return Err(Error::Failed(
+            "No puzzle descriptions downloaded yet. Try `cargo download --all`.".into(),
+        ));
+    }
+
+    match command {
+        PuzzlesCommand::List => {
+            for document in &documents {
+                println!("Day {}: {}", document.day, title(document));
+            }
+        }
+        PuzzlesCommand::Search { query } => {
+            let hits = search::search(&documents, &search::terms(&query));
+
+            if hits.is_empty() {
+                println!("No puzzle matches \"{query}\".");
+                return Ok(());
+            }
+
+            for hit in hits {
+                println!(
+                    "{ANSI_BOLD}Day {}: {}{ANSI_RESET}",
+                    hit.document.day,
+                    title(hit.document)
+                );
+                for snippet in &hit.snippets {
+                    println!("  {snippet}");
+                }
+                println!();
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn title(document: &Document) -> String {
+    document
+        .title
+        .clone()
+        .unwrap_or_else(|| format!("{ANSI_ITALIC}untitled{ANSI_RESET}"))
+}
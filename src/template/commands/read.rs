@@ -1,15 +1,56 @@
-use std::process;
+use std::{
+    fs,
+    io::Write,
+    path::Path,
+    process::{Command, Stdio},
+};
 
-use crate::template::{aoc_cli, Day};
+use crate::template::{answers, aoc_cli, error::Error, markdown, stats, Day};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+pub fn handle(day: Day, pager: bool) -> Result<(), Error> {
+    let puzzle_path = aoc_cli::get_puzzle_path(day);
+
+    // only reach out to the network if the puzzle has not been downloaded yet.
+    if !Path::new(&puzzle_path).exists() {
+        aoc_cli::check()?;
+        aoc_cli::download_puzzle(day)?;
+    }
+
+    let puzzle = fs::read_to_string(&puzzle_path).map_err(|e| {
+        Error::io(
+            format!("Failed to read puzzle description \"{puzzle_path}\""),
+            e,
+        )
+    })?;
+
+    stats::mark_started(day);
+
+    let rendered = markdown::render(&puzzle, markdown::terminal_width());
+
+    if !pager || page(&rendered).is_err() {
+        println!("{rendered}");
+    }
+
+    answers::harvest(day);
+    Ok(())
+}
+
+/// Pipes text into `$PAGER`, defaulting to `less -R` so that ANSI styles are kept.
+fn page(text: &str) -> Result<(), std::io::Error> {
+    let pager = std::env::var("PAGER").unwrap_or_else(|_| "less -R".into());
+    let mut parts = pager.split_whitespace();
+    let program = parts.next().unwrap_or("less");
+
+    let mut cmd = Command::new(program)
+        .args(parts)
+        .stdin(Stdio::piped())
+        .spawn()?;
+
+    if let Some(mut stdin) = cmd.stdin.take() {
+        // the pager may be closed before it has read everything.
+        let _ = writeln!(stdin, "{text}");
     }
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
+    cmd.wait()?;
+    Ok(())
 }
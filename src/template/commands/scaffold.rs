@@ -1,13 +1,27 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
-    process,
+    path::Path,
 };
 
-use crate::template::Day;
+use crate::template::{
+    config,
+    error::Error,
+    hooks::{self, Hook},
+    input_shape::{self, Shape},
+    module_template::{self, ModuleTemplate},
+    puzzle::Puzzle,
+    stats, Day,
+};
 
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+#[derive(Clone, Debug, Default)]
+pub struct ScaffoldOptions {
+    /// The name of a template in the templates folder. Takes precedence over the template
+    /// that matches the shape of a downloaded input, and over `defaults.template` of the configuration.
+    pub template: Option<String>,
+    /// Values for template variables, passed as `--var NAME=value`.
+    pub variables: Vec<(String, String)>,
+}
 
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
@@ -17,53 +31,85 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create(true).open(path)
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+pub fn handle(day: Day, options: &ScaffoldOptions) -> Result<(), Error> {
+    let config = config::get();
+    let input_path = config.input_path(day);
+    let example_path = config.example_path(day);
+    let module_path = config.bin_path(day);
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
-        }
+    // render first, so that a broken template does not leave an empty module behind.
+    let shape = match options.template {
+        Some(_) => None,
+        None => fs::read_to_string(&input_path)
+            .ok()
+            .and_then(|input| input_shape::detect(&input)),
     };
+    let name = options
+        .template
+        .as_deref()
+        .or(shape.map(Shape::template_name))
+        .or(config.defaults.template.as_deref())
+        .unwrap_or(module_template::DEFAULT_NAME);
+    let template = ModuleTemplate::load(name).map_err(Error::Usage)?;
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
-        }
-    }
+    let title = Puzzle::read(day).ok().and_then(|puzzle| puzzle.title());
+    let mut values = module_template::day_values(day, config.year, title);
+    values.extend(options.variables.iter().cloned());
+    let contents = template.render(&values).map_err(Error::Usage)?;
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
-        }
+    let mut file =
+        safe_create_file(&module_path).map_err(|e| Error::io("Failed to create module file", e))?;
+
+    file.write_all(contents.as_bytes())
+        .map_err(|e| Error::io("Failed to write module contents", e))?;
+    if let Some(shape) = shape {
+        println!(
+            "Created module file \"{}\" from template \"{name}\", the input is {}",
+            &module_path,
+            shape.description()
+        );
+    } else if name == module_template::DEFAULT_NAME {
+        println!("Created module file \"{}\"", &module_path);
+    } else {
+        println!(
+            "Created module file \"{}\" from template \"{name}\"",
+            &module_path
+        );
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
-        }
+    // the input may already be downloaded.
+    if !Path::new(&input_path).exists() {
+        create_file(&input_path).map_err(|e| Error::io("Failed to create input file", e))?;
+        println!("Created empty input file \"{}\"", &input_path);
     }
 
+    create_file(&example_path).map_err(|e| Error::io("Failed to create example file", e))?;
+    println!("Created empty example file \"{}\"", &example_path);
+
+    stats::mark_started(day);
+
     println!("---");
     println!("🎄 Type `cargo solve {day}` to run your solution.");
+
+    hooks::run(Hook::PostScaffold, &hooks::Context::day(day));
+    Ok(())
+}
+
+/// Scaffolds several days, skipping days that already have a solution.
+pub fn handle_many(
+    days: impl Iterator<Item = Day>,
+    options: &ScaffoldOptions,
+) -> Result<(), Error> {
+    for day in days {
+        let module_path = config::get().bin_path(day);
+        if Path::new(&module_path).exists() {
+            println!("Skipped day {day}, \"{module_path}\" already exists.");
+            continue;
+        }
+
+        handle(day, options)?;
+        println!();
+    }
+
+    Ok(())
 }
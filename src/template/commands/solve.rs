@@ -1,8 +1,18 @@
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::{
+    error::Error,
+    hooks::{self, Hook},
+    Day, InputSource,
+};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
+pub fn handle(
+    day: Day,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    input: &InputSource,
+) -> Result<(), Error> {
     let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
 
     if dhat {
@@ -17,18 +27,40 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
     }
 
     cmd_args.push("--".to_string());
+    cmd_args.extend(input.to_args());
 
     if let Some(submit_part) = submit_part {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
     }
 
-    let mut cmd = Command::new("cargo")
+    hooks::run(Hook::PreSolve, &hooks::Context::day(day));
+
+    let status = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
-        .spawn()
-        .unwrap();
+        .status()
+        .map_err(|e| Error::io("Failed to run cargo", e))?;
+
+    hooks::run(
+        Hook::PostSolve,
+        &hooks::Context {
+            outcome: Some(
+                if status.success() {
+                    "success"
+                } else {
+                    "failure"
+                }
+                .into(),
+            ),
+            ..hooks::Context::day(day)
+        },
+    );
 
-    cmd.wait().unwrap();
+    if status.success() {
+        Ok(())
+    } else {
+        Err(Error::Solution(vec![day]))
+    }
 }
@@ -0,0 +1,29 @@
+use crate::template::{
+    error::Error,
+    markdown,
+    stats::{self, Stats},
+    ANSI_BOLD, ANSI_RESET,
+};
+
+pub fn handle() -> Result<(), Error> {
+    let stats = Stats::read_from_file();
+
+    if stats.data.is_empty() {
+        println!("No solve stats recorded yet. Stats are recorded when scaffolding a day and submitting answers.");
+        return Ok(());
+    }
+
+    println!("{ANSI_BOLD}Time to solve{ANSI_RESET}");
+    print!("{}", stats::render_table(&stats));
+    println!();
+
+    println!("{ANSI_BOLD}Event{ANSI_RESET}");
+    print!(
+        "{}",
+        stats::render_distribution(&stats, markdown::terminal_width())
+    );
+    println!();
+
+    print!("{}", stats::render_summary(&stats));
+    Ok(())
+}
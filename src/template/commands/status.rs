@@ -0,0 +1,53 @@
+use std::{
+    collections::BTreeSet,
+    io::{stdout, Write},
+    process::{Command, Stdio},
+};
+
+use crate::template::{
+    answers::Answers,
+    error::Error,
+    stats::Stats,
+    status::{self, DayStatus},
+    timings::Timings,
+    Day, ANSI_ITALIC, ANSI_RESET,
+};
+
+pub fn handle(days: &BTreeSet<Day>, run_tests: bool) -> Result<(), Error> {
+    let answers = Answers::read_from_file();
+    let stats = Stats::read_from_file();
+    let timings = Timings::read_from_file();
+
+    let mut rows: Vec<DayStatus> = days
+        .iter()
+        .map(|day| DayStatus::collect(*day, &answers, &stats, &timings))
+        .collect();
+
+    if run_tests {
+        for row in rows.iter_mut().filter(|row| row.scaffolded) {
+            print!(
+                "\r{ANSI_ITALIC}Running tests of day {}...{ANSI_RESET}",
+                row.day
+            );
+            let _ = stdout().flush();
+            row.tests = Some(tests_pass(row.day));
+        }
+        print!("\r\x1b[2K");
+    }
+
+    print!("{}", status::render(&rows));
+
+    let done = rows.iter().filter(|row| row.is_done()).count();
+    println!();
+    println!("{done}/{} days done.", rows.len());
+    Ok(())
+}
+
+fn tests_pass(day: Day) -> bool {
+    Command::new("cargo")
+        .args(["test", "--quiet", "--bin", &day.to_string()])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
@@ -0,0 +1,89 @@
+use std::{
+    collections::BTreeSet,
+    path::Path,
+    process::{Command, Stdio},
+};
+
+use crate::template::{
+    config,
+    error::Error,
+    test_report::{self, Outcome},
+    Day, ANSI_BOLD, ANSI_RESET,
+};
+
+/// Runs the tests of each day and reports a result per part.
+/// Tests that fail because their example is missing or empty are reported as skipped.
+pub fn handle(days: &BTreeSet<Day>, release: bool) -> Result<(), Error> {
+    let config = config::get();
+    let days: Vec<Day> = days
+        .iter()
+        .copied()
+        .filter(|day| Path::new(&config.bin_path(*day)).exists())
+        .collect();
+
+    if days.is_empty() {
+        return Err(Error::Usage(
+            "None of the selected days has a solution yet.".into(),
+        ));
+    }
+
+    let mut failed = vec![];
+
+    for (i, day) in days.iter().copied().enumerate() {
+        if i > 0 {
+            println!();
+        }
+
+        let output = Command::new("cargo")
+            .args(cargo_args(day, release))
+            .env("RUST_BACKTRACE", "0")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .map_err(|e| Error::io("Failed to run cargo", e))?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let cases = test_report::parse_output(&stdout);
+
+        // without any test results, the tests did not build.
+        if cases.is_empty() && !output.status.success() {
+            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+            println!("  ✖ failed to build");
+            for line in String::from_utf8_lossy(&output.stderr)
+                .lines()
+                .filter(|l| l.starts_with("error"))
+            {
+                println!("    {line}");
+            }
+            failed.push(day);
+            continue;
+        }
+
+        print!("{}", test_report::render(day, &cases));
+
+        if cases
+            .iter()
+            .any(|case| matches!(case.outcome, Outcome::Failed { .. }))
+        {
+            failed.push(day);
+        }
+    }
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        let failed: Vec<String> = failed.iter().map(ToString::to_string).collect();
+        Err(Error::Failed(format!(
+            "Tests failed for day(s) {}.",
+            failed.join(", ")
+        )))
+    }
+}
+
+fn cargo_args(day: Day, release: bool) -> Vec<String> {
+    let mut args = vec!["test".to_string(), "--bin".to_string(), day.to_string()];
+    if release {
+        args.push("--release".to_string());
+    }
+    args
+}
@@ -1,40 +1,59 @@
-use std::collections::HashSet;
+use std::collections::BTreeSet;
 
 use crate::template::run_multi::run_multi;
 use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::{
+    all_days, config,
+    error::Error,
+    git,
+    hooks::{self, Hook},
+    readme_benchmarks, Day,
+};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
+pub fn handle(days: Option<BTreeSet<Day>>, run_all: bool, store: bool) -> Result<(), Error> {
     let stored_timings = Timings::read_from_file();
 
-    let days_to_run = day.map_or_else(
-        || {
-            if run_all {
-                all_days().collect()
-            } else {
-                // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
-                    .collect()
-            }
-        },
-        |day| HashSet::from([day]),
-    );
-
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let days_to_run = days.unwrap_or_else(|| {
+        if run_all {
+            all_days().collect()
+        } else {
+            // when the `--all` flag is not set, filter out days that are fully benched.
+            all_days()
+                .filter(|day| !stored_timings.is_day_complete(*day))
+                .collect()
+        }
+    });
+
+    let run = run_multi(&days_to_run, true, true)?;
+    let timings = run.timings.unwrap_or_default();
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings
+            .store_file()
+            .map_err(|e| Error::io("Failed to store timings", e))?;
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
-            Ok(()) => {
-                println!("Stored updated benchmarks.");
-            }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
-            }
+        readme_benchmarks::update(merged_timings)?;
+        println!("Stored updated benchmarks.");
+
+        if config::get().git.auto_commit {
+            git::commit_benchmarks();
         }
+
+        hooks::run(
+            Hook::PostStore,
+            &hooks::Context {
+                days: timings.data.iter().map(|t| t.day).collect(),
+                outcome: Some("stored".into()),
+                ..hooks::Context::default()
+            },
+        );
+    }
+
+    if run.failed.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::Solution(run.failed))
     }
 }
@@ -0,0 +1,200 @@
+use std::{
+    io::{stdout, Write},
+    path::Path,
+    time::Duration,
+};
+
+use chrono::{DateTime, Utc};
+
+use crate::template::{
+    answers, aoc_cli,
+    commands::{read, scaffold},
+    config,
+    error::Error,
+    leaderboard::format_duration,
+    Day, ANSI_BOLD, ANSI_RESET,
+};
+
+/// How often to retry the download if the server is not ready right after the unlock.
+const DOWNLOAD_RETRIES: u32 = 5;
+const DOWNLOAD_RETRY_DELAY: Duration = Duration::from_secs(5);
+
+/// Source of the current time, injectable so that waiting can be tested.
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+    fn sleep(&self, duration: Duration);
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
+}
+
+pub fn handle(wait: bool) -> Result<(), Error> {
+    let clock = SystemClock;
+
+    let day = if wait {
+        let config = config::get();
+        let is_fetched = |day| {
+            Path::new(&config.input_path(day)).exists() && Path::new(&config.bin_path(day)).exists()
+        };
+        let Some(day) = wait_for_day(&clock, is_fetched) else {
+            return Err(Error::Failed("Could not determine the next unlock.".into()));
+        };
+        day
+    } else {
+        match Day::today_at(clock.now()) {
+            Some(day) => day,
+            None => {
+                return Err(Error::Usage(
+                    "`today` command can only be run between the 1st and \
+                    the 25th of december. Please use `scaffold` with a specific day \
+                    or `today --wait` to wait for the next puzzle."
+                        .into(),
+                ));
+            }
+        }
+    };
+
+    // download first, so that the scaffolded template can match the shape of the input.
+    let downloaded = aoc_cli::check().and_then(|()| download_with_retry(&clock, day));
+    scaffold::handle(day, &scaffold::ScaffoldOptions::default())?;
+    downloaded?;
+    answers::harvest(day);
+
+    read::handle(day, false)
+}
+
+/// Returns today's puzzle if it is not fetched yet, otherwise waits for the next one to unlock.
+pub fn wait_for_day(clock: &impl Clock, is_fetched: impl Fn(Day) -> bool) -> Option<Day> {
+    if let Some(day) = Day::today_at(clock.now()).filter(|day| !is_fetched(*day)) {
+        return Some(day);
+    }
+
+    let (day, unlock) = Day::next_unlock(clock.now())?;
+    wait_until(clock, day, unlock);
+    Some(day)
+}
+
+/// Shows a countdown until `unlock`, returning once it has passed.
+pub fn wait_until(clock: &impl Clock, day: Day, unlock: DateTime<Utc>) {
+    let mut stdout = stdout();
+
+    while clock.now() < unlock {
+        let remaining = unlock - clock.now();
+
+        print!(
+            "\r⏳ Day {day} unlocks in {ANSI_BOLD}{}{ANSI_RESET} ",
+            format_duration(remaining.num_seconds())
+        );
+        let _ = stdout.flush();
+
+        clock.sleep(
+            remaining
+                .to_std()
+                .unwrap_or_default()
+                .min(Duration::from_secs(1)),
+        );
+    }
+
+    println!("\r🎄 Day {day} is unlocked!                      ");
+}
+
+/// Downloads a day, retrying a few times in case the puzzle is not available right after the unlock.
+pub fn download_with_retry(clock: &impl Clock, day: Day) -> Result<(), aoc_cli::AocCommandError> {
+    let mut attempt = 1;
+
+    loop {
+        match aoc_cli::download(day) {
+            Ok(_) => return Ok(()),
+            Err(e) if attempt >= DOWNLOAD_RETRIES => return Err(e),
+            Err(e) => {
+                eprintln!("Download failed ({e}), retrying in {DOWNLOAD_RETRY_DELAY:?}...");
+                clock.sleep(DOWNLOAD_RETRY_DELAY);
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{wait_for_day, wait_until, Clock};
+    use crate::day;
+    use chrono::{DateTime, Utc};
+    use std::{cell::RefCell, time::Duration};
+
+    struct FakeClock {
+        now: RefCell<DateTime<Utc>>,
+        sleeps: RefCell<u32>,
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> DateTime<Utc> {
+            *self.now.borrow()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            *self.sleeps.borrow_mut() += 1;
+            *self.now.borrow_mut() += chrono::Duration::from_std(duration).unwrap();
+        }
+    }
+
+    #[test]
+    fn waits_until_unlock() {
+        let unlock: DateTime<Utc> = "2018-12-05T05:00:00Z".parse().unwrap();
+        let clock = FakeClock {
+            now: RefCell::new(unlock - chrono::Duration::milliseconds(2500)),
+            sleeps: RefCell::new(0),
+        };
+
+        wait_until(&clock, day!(5), unlock);
+
+        assert_eq!(clock.now(), unlock);
+        assert_eq!(*clock.sleeps.borrow(), 3);
+    }
+
+    #[test]
+    fn returns_immediately_after_unlock() {
+        let unlock: DateTime<Utc> = "2018-12-05T05:00:00Z".parse().unwrap();
+        let clock = FakeClock {
+            now: RefCell::new(unlock),
+            sleeps: RefCell::new(0),
+        };
+
+        wait_until(&clock, day!(5), unlock);
+
+        assert_eq!(*clock.sleeps.borrow(), 0);
+    }
+
+    #[test]
+    fn fetches_today_before_waiting() {
+        let clock = FakeClock {
+            now: RefCell::new("2018-12-05T06:00:00Z".parse().unwrap()),
+            sleeps: RefCell::new(0),
+        };
+
+        assert_eq!(wait_for_day(&clock, |_| false), Some(day!(5)));
+        assert_eq!(*clock.sleeps.borrow(), 0);
+    }
+
+    #[test]
+    fn waits_for_the_next_day_once_today_is_fetched() {
+        let clock = FakeClock {
+            now: RefCell::new("2018-12-06T04:59:58Z".parse().unwrap()),
+            sleeps: RefCell::new(0),
+        };
+
+        assert_eq!(wait_for_day(&clock, |day| day == day!(5)), Some(day!(6)));
+        assert_eq!(*clock.sleeps.borrow(), 2);
+    }
+}
@@ -0,0 +1,252 @@
+use std::{
+    fmt::Write as _,
+    fs,
+    path::PathBuf,
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::{
+    config, error::Error, markdown::strip_ansi, Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+
+/// How often to check the watched files for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// Editors often write a file in several steps, wait for them to finish before rebuilding.
+const SETTLE_DELAY: Duration = Duration::from_millis(200);
+
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+type Answers = [Option<String>; 2];
+
+/// Reruns the solution of a day whenever its source, the library or its data files change.
+/// Uses polling instead of file system events, so it works in containers and on network drives.
+/// Runs until interrupted: failing runs are shown and watching continues.
+pub fn handle(day: Day, release: bool, run_tests: bool) -> Result<(), Error> {
+    let mut previous: Option<Answers> = None;
+    let mut snapshot = modification_times(day);
+
+    loop {
+        print!("{CLEAR_SCREEN}");
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET} {ANSI_ITALIC}(watching for changes, press Ctrl-C to stop){ANSI_RESET}");
+        println!();
+
+        if run_tests {
+            run_tests_for(day, release);
+            println!();
+        }
+
+        let answers = run(day, release);
+        if let Some(answers) = &answers {
+            println!();
+            print!("{}", render_diff(previous.as_ref(), answers));
+            previous = Some(answers.clone());
+        }
+
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let current = modification_times(day);
+            if current != snapshot {
+                thread::sleep(SETTLE_DELAY);
+                snapshot = modification_times(day);
+                break;
+            }
+        }
+    }
+}
+
+/// The files that influence the result of a day.
+fn watched_files(day: Day) -> Vec<PathBuf> {
+    let config = config::get();
+
+    let mut files: Vec<PathBuf> = vec![
+        config.bin_path(day).into(),
+        "src/lib.rs".into(),
+        config.input_path(day).into(),
+        config.example_path(day).into(),
+    ];
+
+    // additional examples, e.g. `01-2.txt`.
+    if let Ok(entries) = fs::read_dir(config.folder("examples")) {
+        let prefix = format!("{day}-");
+        files.extend(
+            entries
+                .filter_map(Result::ok)
+                .filter(|e| e.file_name().to_string_lossy().starts_with(&prefix))
+                .map(|e| e.path()),
+        );
+    }
+
+    files.sort();
+    files
+}
+
+fn modification_times(day: Day) -> Vec<(PathBuf, Option<SystemTime>)> {
+    watched_files(day)
+        .into_iter()
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+fn cargo_args(command: &str, day: Day, release: bool) -> Vec<String> {
+    let mut args = vec![
+        command.to_string(),
+        "--quiet".to_string(),
+        "--bin".to_string(),
+        day.to_string(),
+    ];
+    if release {
+        args.push("--release".to_string());
+    }
+    args
+}
+
+fn run_tests_for(day: Day, release: bool) {
+    println!("{ANSI_BOLD}Tests{ANSI_RESET}");
+
+    let status = Command::new("cargo")
+        .args(cargo_args("test", day, release))
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status();
+
+    if let Err(e) = status {
+        eprintln!("Failed to run tests: {e}");
+    }
+}
+
+/// Builds and runs the solution, echoing its output. Returns the answers if the run succeeded.
+fn run(day: Day, release: bool) -> Option<Answers> {
+    println!("{ANSI_BOLD}Solution{ANSI_RESET}");
+
+    let output = Command::new("cargo")
+        .args(cargo_args("run", day, release))
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .output();
+
+    match output {
+        Ok(output) => {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            print!("{stdout}");
+            output.status.success().then(|| parse_answers(&stdout))
+        }
+        Err(e) => {
+            eprintln!("Failed to run solution: {e}");
+            None
+        }
+    }
+}
+
+/// Extracts the answers from the output of the runner.
+/// Results are printed as `Part 1: 42 (1.2ms)`, or as `Part 1: ▼ (1.2ms)` followed by the lines of a multi-line result.
+fn parse_answers(output: &str) -> Answers {
+    let output = strip_ansi(output);
+    let mut answers: Answers = [None, None];
+    let mut multiline: Option<usize> = None;
+
+    // intermediate results are overwritten with `\r`, only the last write of a line counts.
+    for line in output.lines().filter_map(|l| l.rsplit('\r').next()) {
+        let part = line
+            .strip_prefix("Part ")
+            .and_then(|rest| rest.split_once(": "))
+            .and_then(|(part, rest)| Some((part.parse::<usize>().ok()?, rest)));
+
+        match part {
+            Some((part @ 1..=2, rest)) => {
+                let value = strip_duration(rest).trim();
+                multiline = None;
+                if value == "▼" {
+                    multiline = Some(part - 1);
+                    answers[part - 1] = Some(String::new());
+                } else if value != "✖" {
+                    answers[part - 1] = Some(value.to_string());
+                }
+            }
+            _ => {
+                if let Some(answer) = multiline.and_then(|i| answers[i].as_mut()) {
+                    if !answer.is_empty() {
+                        answer.push('\n');
+                    }
+                    answer.push_str(line);
+                }
+            }
+        }
+    }
+
+    answers
+}
+
+/// Removes the trailing ` (1.2ms)` or ` (1.2ms @ 10 samples)` of a result.
+fn strip_duration(s: &str) -> &str {
+    match s.rfind(" (") {
+        Some(pos) if s.ends_with(')') => &s[..pos],
+        _ => s,
+    }
+}
+
+/// Renders a compact comparison of the answers with the previous run.
+fn render_diff(previous: Option<&Answers>, current: &Answers) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "{ANSI_BOLD}Changes{ANSI_RESET}");
+
+    for (i, answer) in current.iter().enumerate() {
+        let before = previous.and_then(|p| p[i].as_deref());
+        let now = answer.as_deref();
+        let format = |a: Option<&str>| a.map_or("✖".to_string(), |a| a.replace('\n', "⏎"));
+
+        let change = match (previous, before == now) {
+            (None, _) => "first run".to_string(),
+            (Some(_), true) => "unchanged".to_string(),
+            (Some(_), false) => format!("was {}", format(before)),
+        };
+
+        let _ = writeln!(
+            out,
+            "Part {}: {ANSI_BOLD}{}{ANSI_RESET} {ANSI_ITALIC}({change}){ANSI_RESET}",
+            i + 1,
+            format(now)
+        );
+    }
+
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_answers, render_diff};
+    use crate::template::markdown::strip_ansi;
+
+    #[test]
+    fn parses_answers() {
+        let output = "Part 1: 42\r\x1b[1mPart 1: 42\x1b[0m (1.2ms)\nPart 2: ✖             \n";
+        assert_eq!(parse_answers(output), [Some("42".into()), None]);
+    }
+
+    #[test]
+    fn parses_multiline_answers() {
+        let output = "Part 1: 7 (3.0µs @ 10 samples)\nPart 2: ▼\rPart 2: ▼ (1.0ms)\n#.#\n.#.\n";
+        assert_eq!(
+            parse_answers(output),
+            [Some("7".into()), Some("#.#\n.#.".into())]
+        );
+    }
+
+    #[test]
+    fn renders_diff() {
+        let previous = [Some("1".to_string()), Some("2".to_string())];
+        let current = [Some("1".to_string()), Some("3".to_string())];
+        let diff = strip_ansi(&render_diff(Some(&previous), &current));
+        assert!(diff.contains("Part 1: 1 (unchanged)"));
+        assert!(diff.contains("Part 2: 3 (was 2)"));
+
+        let diff = strip_ansi(&render_diff(None, &current));
+        assert!(diff.contains("Part 2: 3 (first run)"));
+    }
+}
@@ -0,0 +1,527 @@
+/// Module that loads the project configuration from `aoc.json`.
+/// Every setting is optional, missing keys fall back to the defaults of the template.
+use std::{
+    collections::HashMap,
+    env, fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::OnceLock,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{
+    accounts::{Account, Accounts},
+    Day,
+};
+
+pub static CONFIG_FILE_PATH: &str = "aoc.json";
+
+static CONFIG: OnceLock<Result<Config, String>> = OnceLock::new();
+
+/// Loads the project configuration on first use. Fails if `aoc.json` does not parse.
+pub fn load() -> Result<&'static Config, String> {
+    CONFIG
+        .get_or_init(Config::read_from_file)
+        .as_ref()
+        .map_err(Clone::clone)
+}
+
+/// Returns the project configuration. Commands check it with `load` before they run,
+/// so this only panics for a broken `aoc.json` elsewhere, e.g. in solutions.
+pub fn get() -> &'static Config {
+    load().unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Directories and files that the template reads and writes, relative to the project root.
+#[derive(Clone, Debug)]
+pub struct Paths {
+    pub data: String,
+    pub inputs: String,
+    pub examples: String,
+    pub puzzles: String,
+    pub bin: String,
+    pub readme: String,
+    /// Templates for `cargo scaffold`, see the `module_template` module.
+    pub templates: String,
+}
+
+impl Default for Paths {
+    fn default() -> Self {
+        Self {
+            data: "data".into(),
+            inputs: "data/inputs".into(),
+            examples: "data/examples".into(),
+            puzzles: "data/puzzles".into(),
+            bin: "src/bin".into(),
+            readme: "README.md".into(),
+            templates: "templates".into(),
+        }
+    }
+}
+
+/// Settings for benchmarking solutions with `cargo time`.
+#[derive(Clone, Debug)]
+pub struct Benchmark {
+    pub min_samples: u128,
+    pub max_samples: u128,
+    /// The approximate time to spend benching a part.
+    pub target_millis: u128,
+}
+
+impl Default for Benchmark {
+    fn default() -> Self {
+        Self {
+            min_samples: 10,
+            max_samples: 10000,
+            target_millis: 1000,
+        }
+    }
+}
+
+/// Flags that are enabled by default for every invocation.
+#[derive(Clone, Debug, Default)]
+pub struct Defaults {
+    pub release: bool,
+    pub store: bool,
+    pub pager: bool,
+    /// The template `cargo scaffold` uses without `--template`.
+    pub template: Option<String>,
+}
+
+/// Shell commands that run around template actions, see the `hooks` module.
+#[derive(Clone, Debug, Default)]
+pub struct Hooks {
+    pub post_scaffold: Option<String>,
+    pub pre_solve: Option<String>,
+    pub post_solve: Option<String>,
+    pub post_submit: Option<String>,
+    pub post_store: Option<String>,
+}
+
+/// Settings for committing solutions and benchmarks automatically.
+#[derive(Clone, Debug, Default)]
+pub struct Git {
+    /// Commit after an answer is accepted and after `cargo time --store`.
+    pub auto_commit: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub year: Option<u16>,
+    /// The site that pages are fetched from. Can point to a local stand-in server.
+    pub base_url: String,
+    pub session_file: Option<String>,
+    pub accounts_file: Option<String>,
+    /// The account in use, selected with `cargo account select` or the `AOC_ACCOUNT` environment variable.
+    pub account: Option<Account>,
+    /// An account that is selected but missing from the accounts file.
+    /// Commands that need a session fail for it rather than act as the default session.
+    pub unknown_account: Option<String>,
+    pub paths: Paths,
+    pub benchmark: Benchmark,
+    pub defaults: Defaults,
+    pub hooks: Hooks,
+    pub git: Git,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            year: None,
+            base_url: "https://adventofcode.com".into(),
+            session_file: None,
+            accounts_file: None,
+            account: None,
+            unknown_account: None,
+            paths: Paths::default(),
+            benchmark: Benchmark::default(),
+            defaults: Defaults::default(),
+            hooks: Hooks::default(),
+            git: Git::default(),
+        }
+    }
+}
+
+impl Config {
+    /// Reads `aoc.json`. If not present, returns the default configuration.
+    /// The `AOC_YEAR` environment variable takes precedence over the configured year.
+    /// If an account is in use, inputs are read from a subdirectory named after it.
+    fn read_from_file() -> Result<Self, String> {
+        let mut config = match fs::read_to_string(CONFIG_FILE_PATH) {
+            Ok(s) => Config::try_from(s)
+                .map_err(|e| format!("Failed to parse \"{CONFIG_FILE_PATH}\": {e}"))?,
+            Err(_) => Config::default(),
+        };
+
+        if let Some(year) = env::var("AOC_YEAR").ok().and_then(|y| y.parse().ok()) {
+            config.year = Some(year);
+        }
+
+        if let Some(path) = config.accounts_file_path() {
+            let accounts = Accounts::read_from_file(&path);
+            let name = env::var("AOC_ACCOUNT").ok().or(accounts.selected.clone());
+
+            if let Some(name) = name {
+                match accounts.get(&name) {
+                    Some(account) => config.account = Some(account.clone()),
+                    None => config.unknown_account = Some(name),
+                }
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// Resolves a data folder by name, e.g. `inputs` or `examples`.
+    pub fn folder(&self, name: &str) -> PathBuf {
+        match name {
+            "inputs" => PathBuf::from(self.inputs_folder()),
+            "examples" => PathBuf::from(&self.paths.examples),
+            "puzzles" => PathBuf::from(self.puzzles_folder()),
+            name => PathBuf::from(&self.paths.data).join(name),
+        }
+    }
+
+    /// Returns the subfolder of the account in use, e.g. `data/inputs/alice`. Without accounts, this is `folder`.
+    /// Inputs, puzzle descriptions, answers and stats differ between accounts.
+    fn account_folder(&self, folder: &str) -> String {
+        match &self.account {
+            Some(account) => format!("{folder}/{}", account.name),
+            None => folder.to_string(),
+        }
+    }
+
+    /// Returns the inputs folder of the account in use. Without accounts, this is `paths.inputs`.
+    pub fn inputs_folder(&self) -> String {
+        self.account_folder(&self.paths.inputs)
+    }
+
+    pub fn account_inputs_folder(&self, name: &str) -> String {
+        format!("{}/{name}", self.paths.inputs)
+    }
+
+    /// Returns the puzzles folder of the account in use, as a description includes the answers of the account.
+    pub fn puzzles_folder(&self) -> String {
+        self.account_folder(&self.paths.puzzles)
+    }
+
+    /// Returns the folders an account keeps its own files in, e.g. to create them when it is added.
+    pub fn account_folders(&self, name: &str) -> [String; 4] {
+        [
+            &self.paths.inputs,
+            &self.paths.puzzles,
+            &self.paths.examples,
+            &self.paths.data,
+        ]
+        .map(|folder| format!("{folder}/{name}"))
+    }
+
+    pub fn input_path(&self, day: Day) -> String {
+        format!("{}/{day}.txt", self.inputs_folder())
+    }
+
+    pub fn example_path(&self, day: Day) -> String {
+        format!("{}/{day}.txt", self.paths.examples)
+    }
+
+    pub fn puzzle_path(&self, day: Day) -> String {
+        format!("{}/{day}.md", self.puzzles_folder())
+    }
+
+    /// Returns the path of the example manifest of the account in use.
+    pub fn example_manifest_path(&self) -> String {
+        format!(
+            "{}/manifest.json",
+            self.account_folder(&self.paths.examples)
+        )
+    }
+
+    pub fn bin_path(&self, day: Day) -> String {
+        format!("{}/{day}.rs", self.paths.bin)
+    }
+
+    /// Returns the path of the session cookie file, defaulting to `~/.adventofcode.session` like aoc-cli.
+    pub fn session_file_path(&self) -> Option<PathBuf> {
+        expand_home(self.session_file.as_deref(), ".adventofcode.session")
+    }
+
+    /// Returns the path of the file that stores named accounts, defaulting to `~/.adventofcode.accounts.json`.
+    pub fn accounts_file_path(&self) -> Option<PathBuf> {
+        expand_home(self.accounts_file.as_deref(), ".adventofcode.accounts.json")
+    }
+
+    /// Returns the path of a file in the data directory, e.g. `timings.json`.
+    pub fn data_file(&self, name: &str) -> String {
+        format!("{}/{name}", self.paths.data)
+    }
+
+    /// Returns the path of a file in the data directory that belongs to the account in use, e.g. `answers.json`.
+    pub fn account_data_file(&self, name: &str) -> String {
+        format!("{}/{name}", self.account_folder(&self.paths.data))
+    }
+}
+
+/// Writes a JSON store of the data directory, e.g. `answers.json`.
+/// Creates its folder first, as the folder of an account only exists once something is stored for it.
+pub fn store_json(path: &str, json: &JsonValue) -> io::Result<()> {
+    if let Some(folder) = Path::new(path).parent() {
+        fs::create_dir_all(folder)?;
+    }
+    let mut file = fs::File::create(path)?;
+    json.format_to(&mut file)
+}
+
+/// Resolves a configured path that may start with `~/`, or a default file in the home directory.
+fn expand_home(path: Option<&str>, default: &str) -> Option<PathBuf> {
+    let home = env::var("HOME").or_else(|_| env::var("USERPROFILE")).ok();
+
+    match (path, home) {
+        (Some(path), Some(home)) if path.starts_with("~/") => {
+            Some(PathBuf::from(home).join(&path[2..]))
+        }
+        (Some(path), _) => Some(PathBuf::from(path)),
+        (None, Some(home)) => Some(PathBuf::from(home).join(default)),
+        (None, None) => None,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn get_string(json: &HashMap<String, JsonValue>, key: &str) -> Result<Option<String>, String> {
+    match json.get(key) {
+        None | Some(JsonValue::Null) => Ok(None),
+        Some(JsonValue::String(s)) => Ok(Some(s.clone())),
+        Some(_) => Err(format!("expected `{key}` to be a string.")),
+    }
+}
+
+fn get_number(json: &HashMap<String, JsonValue>, key: &str) -> Result<Option<f64>, String> {
+    match json.get(key) {
+        None | Some(JsonValue::Null) => Ok(None),
+        Some(JsonValue::Number(n)) if *n >= 0.0 && n.fract() == 0.0 => Ok(Some(*n)),
+        Some(_) => Err(format!("expected `{key}` to be a positive integer.")),
+    }
+}
+
+fn get_bool(json: &HashMap<String, JsonValue>, key: &str) -> Result<Option<bool>, String> {
+    match json.get(key) {
+        None | Some(JsonValue::Null) => Ok(None),
+        Some(JsonValue::Boolean(b)) => Ok(Some(*b)),
+        Some(_) => Err(format!("expected `{key}` to be a boolean.")),
+    }
+}
+
+fn get_object<'a>(
+    json: &'a HashMap<String, JsonValue>,
+    key: &str,
+) -> Result<Option<&'a HashMap<String, JsonValue>>, String> {
+    match json.get(key) {
+        None | Some(JsonValue::Null) => Ok(None),
+        Some(JsonValue::Object(o)) => Ok(Some(o)),
+        Some(_) => Err(format!("expected `{key}` to be an object.")),
+    }
+}
+
+impl TryFrom<String> for Config {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let mut config = Config::default();
+
+        if let Some(year) = get_number(json, "year")? {
+            config.year =
+                Some(u16::try_from(year as u64).or(Err("expected `year` to be a year."))?);
+        }
+
+        if let Some(base_url) = get_string(json, "base_url")? {
+            config.base_url = base_url.trim_end_matches('/').to_string();
+        }
+
+        config.session_file = get_string(json, "session_file")?;
+        config.accounts_file = get_string(json, "accounts_file")?;
+
+        if let Some(paths) = get_object(json, "paths")? {
+            let p = &mut config.paths;
+            for (key, field) in [
+                ("data", &mut p.data),
+                ("inputs", &mut p.inputs),
+                ("examples", &mut p.examples),
+                ("puzzles", &mut p.puzzles),
+                ("bin", &mut p.bin),
+                ("readme", &mut p.readme),
+                ("templates", &mut p.templates),
+            ] {
+                if let Some(value) = get_string(paths, key)? {
+                    *field = value.trim_end_matches('/').to_string();
+                }
+            }
+        }
+
+        if let Some(benchmark) = get_object(json, "benchmark")? {
+            let b = &mut config.benchmark;
+            for (key, field) in [
+                ("min_samples", &mut b.min_samples),
+                ("max_samples", &mut b.max_samples),
+                ("target_millis", &mut b.target_millis),
+            ] {
+                if let Some(value) = get_number(benchmark, key)? {
+                    *field = value as u128;
+                }
+            }
+        }
+
+        if let Some(defaults) = get_object(json, "defaults")? {
+            let d = &mut config.defaults;
+            for (key, field) in [
+                ("release", &mut d.release),
+                ("store", &mut d.store),
+                ("pager", &mut d.pager),
+            ] {
+                if let Some(value) = get_bool(defaults, key)? {
+                    *field = value;
+                }
+            }
+            d.template = get_string(defaults, "template")?;
+        }
+
+        if let Some(hooks) = get_object(json, "hooks")? {
+            let h = &mut config.hooks;
+            for (key, field) in [
+                ("post_scaffold", &mut h.post_scaffold),
+                ("pre_solve", &mut h.pre_solve),
+                ("post_solve", &mut h.post_solve),
+                ("post_submit", &mut h.post_submit),
+                ("post_store", &mut h.post_store),
+            ] {
+                *field = get_string(hooks, key)?;
+            }
+        }
+
+        if let Some(git) = get_object(json, "git")? {
+            if let Some(auto_commit) = get_bool(git, "auto_commit")? {
+                config.git.auto_commit = auto_commit;
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Account, Config};
+    use crate::day;
+
+    #[test]
+    fn uses_defaults_for_missing_keys() {
+        let config = Config::try_from("{}".to_string()).unwrap();
+        assert_eq!(config.year, None);
+        assert_eq!(config.input_path(day!(1)), "data/inputs/01.txt");
+        assert_eq!(config.bin_path(day!(1)), "src/bin/01.rs");
+        assert_eq!(config.benchmark.max_samples, 10000);
+        assert!(!config.defaults.release);
+        assert!(!config.git.auto_commit);
+    }
+
+    #[test]
+    fn reads_settings() {
+        let json = r#"{
+            "year": 2018,
+            "base_url": "http://localhost:8080/",
+            "session_file": "~/.aoc",
+            "paths": { "inputs": "inputs/", "data": "state" },
+            "benchmark": { "min_samples": 5 },
+            "defaults": { "release": true, "template": "grid" },
+            "hooks": { "post_scaffold": "code src/bin/$AOC_DAY.rs" },
+            "git": { "auto_commit": true }
+        }"#;
+        let config = Config::try_from(json.to_string()).unwrap();
+        assert_eq!(config.year, Some(2018));
+        assert_eq!(config.base_url, "http://localhost:8080");
+        assert_eq!(config.session_file.as_deref(), Some("~/.aoc"));
+        assert_eq!(config.input_path(day!(13)), "inputs/13.txt");
+        assert_eq!(config.puzzle_path(day!(13)), "data/puzzles/13.md");
+        assert_eq!(config.data_file("timings.json"), "state/timings.json");
+        assert_eq!(config.benchmark.min_samples, 5);
+        assert_eq!(config.benchmark.max_samples, 10000);
+        assert!(config.defaults.release);
+        assert_eq!(config.defaults.template.as_deref(), Some("grid"));
+        assert_eq!(
+            config.hooks.post_scaffold.as_deref(),
+            Some("code src/bin/$AOC_DAY.rs")
+        );
+        assert_eq!(config.hooks.pre_solve, None);
+        assert!(config.git.auto_commit);
+    }
+
+    #[test]
+    fn resolves_folders() {
+        let config = Config::default();
+        assert_eq!(config.folder("examples").to_str(), Some("data/examples"));
+        assert_eq!(config.folder("other").to_str(), Some("data/other"));
+    }
+
+    #[test]
+    fn uses_account_inputs() {
+        let config = Config {
+            account: Some(Account {
+                name: "alice".into(),
+                token: "abc".into(),
+            }),
+            ..Config::default()
+        };
+        assert_eq!(config.input_path(day!(1)), "data/inputs/alice/01.txt");
+        assert_eq!(config.folder("inputs").to_str(), Some("data/inputs/alice"));
+        assert_eq!(config.account_inputs_folder("bob"), "data/inputs/bob");
+    }
+
+    #[test]
+    fn uses_account_files() {
+        let config = Config {
+            account: Some(Account {
+                name: "alice".into(),
+                token: "abc".into(),
+            }),
+            ..Config::default()
+        };
+        assert_eq!(config.puzzle_path(day!(1)), "data/puzzles/alice/01.md");
+        assert_eq!(
+            config.folder("puzzles").to_str(),
+            Some("data/puzzles/alice")
+        );
+        assert_eq!(
+            config.example_manifest_path(),
+            "data/examples/alice/manifest.json"
+        );
+        assert_eq!(
+            config.account_data_file("answers.json"),
+            "data/alice/answers.json"
+        );
+        // examples and timings are the same for every account.
+        assert_eq!(config.example_path(day!(1)), "data/examples/01.txt");
+        assert_eq!(config.data_file("timings.json"), "data/timings.json");
+
+        let config = Config::default();
+        assert_eq!(config.puzzle_path(day!(1)), "data/puzzles/01.md");
+        assert_eq!(
+            config.example_manifest_path(),
+            "data/examples/manifest.json"
+        );
+        assert_eq!(config.account_data_file("stats.json"), "data/stats.json");
+    }
+
+    #[test]
+    fn rejects_invalid_types() {
+        assert!(Config::try_from(r#"{ "year": "2018" }"#.to_string()).is_err());
+        assert!(Config::try_from(r#"{ "paths": { "bin": 1 } }"#.to_string()).is_err());
+        assert!(Config::try_from("[]".to_string()).is_err());
+    }
+}
@@ -0,0 +1,339 @@
+/// Module that holds the state of the interactive dashboard and renders it with raw ANSI sequences.
+use crate::template::{
+    all_days,
+    markdown::strip_ansi,
+    status::{DayStatus, PartStatus},
+    Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+
+const ANSI_REVERSE: &str = "\x1b[7m";
+/// Clears the rest of the line, so that a frame can be drawn over the previous one without flicker.
+const CLEAR_LINE: &str = "\x1b[K";
+const CALENDAR_COLUMNS: u8 = 5;
+/// Keep a bounded history of the output pane.
+const MAX_OUTPUT_LINES: usize = 1000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Key {
+    Up,
+    Down,
+    Left,
+    Right,
+    Enter,
+    Char(char),
+}
+
+/// Parses the bytes read from a terminal in raw mode. Unknown escape sequences are dropped.
+pub fn parse_keys(bytes: &[u8]) -> Vec<Key> {
+    let mut keys = vec![];
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            // arrows are sent as `ESC [ A` or, in application mode, as `ESC O A`.
+            0x1b if i + 2 < bytes.len() && matches!(bytes[i + 1], b'[' | b'O') => {
+                match bytes[i + 2] {
+                    b'A' => keys.push(Key::Up),
+                    b'B' => keys.push(Key::Down),
+                    b'C' => keys.push(Key::Right),
+                    b'D' => keys.push(Key::Left),
+                    _ => {}
+                }
+                i += 3;
+                continue;
+            }
+            b'\r' | b'\n' => keys.push(Key::Enter),
+            // ctrl-c arrives as a byte in raw mode.
+            0x03 => keys.push(Key::Char('q')),
+            b if b.is_ascii_graphic() => keys.push(Key::Char(b as char)),
+            _ => {}
+        }
+        i += 1;
+    }
+
+    keys
+}
+
+/// A command the dashboard can run for the selected day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Solve,
+    Test,
+    Benchmark,
+    Read,
+    Submit(u8),
+}
+
+impl Action {
+    pub fn from_key(key: Key) -> Option<Self> {
+        match key {
+            Key::Char('s') | Key::Enter => Some(Action::Solve),
+            Key::Char('t') => Some(Action::Test),
+            Key::Char('b') => Some(Action::Benchmark),
+            Key::Char('r') => Some(Action::Read),
+            Key::Char('1') => Some(Action::Submit(1)),
+            Key::Char('2') => Some(Action::Submit(2)),
+            _ => None,
+        }
+    }
+
+    /// The arguments of the subcommand that performs this action.
+    pub fn args(self, day: Day) -> Vec<String> {
+        let day = day.to_string();
+        match self {
+            Action::Solve => vec!["solve".into(), day],
+            Action::Test => vec!["test-day".into(), day],
+            Action::Benchmark => vec!["time".into(), day],
+            Action::Read => vec!["read".into(), day],
+            Action::Submit(part) => vec!["solve".into(), day, "--submit".into(), part.to_string()],
+        }
+    }
+
+    pub fn label(self) -> String {
+        match self {
+            Action::Solve => "solve".into(),
+            Action::Test => "test".into(),
+            Action::Benchmark => "benchmark".into(),
+            Action::Read => "read".into(),
+            Action::Submit(part) => format!("submit part {part}"),
+        }
+    }
+}
+
+pub struct Dashboard {
+    pub selected: Day,
+    pub statuses: Vec<DayStatus>,
+    /// Puzzle titles by day, if downloaded.
+    pub titles: Vec<(Day, String)>,
+    pub output: Vec<String>,
+    /// The action that is running and the day it runs for.
+    pub running: Option<(Action, Day)>,
+    /// A submission waits for confirmation, as it can not be undone.
+    pub confirm: Option<Action>,
+    /// A one-line message shown above the output, e.g. the exit status of the last action.
+    pub message: String,
+}
+
+impl Dashboard {
+    pub fn new(selected: Day, statuses: Vec<DayStatus>, titles: Vec<(Day, String)>) -> Self {
+        Self {
+            selected,
+            statuses,
+            titles,
+            output: vec![],
+            running: None,
+            confirm: None,
+            message: String::new(),
+        }
+    }
+
+    /// Moves the selection through the calendar, which has five days per row.
+    pub fn move_selection(&mut self, key: Key) {
+        let current = self.selected.into_inner();
+        let target = match key {
+            Key::Left => current.checked_sub(1),
+            Key::Right => Some(current + 1),
+            Key::Up => current.checked_sub(CALENDAR_COLUMNS),
+            Key::Down => Some(current + CALENDAR_COLUMNS),
+            _ => None,
+        };
+
+        if let Some(day) = target.and_then(Day::new) {
+            self.selected = day;
+        }
+    }
+
+    /// Adds a line of output. Progress updates that are overwritten with `\r` only keep their last state.
+    pub fn push_output(&mut self, line: &str) {
+        let line = strip_ansi(line.rsplit('\r').next().unwrap_or_default());
+        self.output.push(line.trim_end().to_string());
+
+        if self.output.len() > MAX_OUTPUT_LINES {
+            self.output.drain(..self.output.len() - MAX_OUTPUT_LINES);
+        }
+    }
+
+    fn status(&self, day: Day) -> Option<&DayStatus> {
+        self.statuses.iter().find(|s| s.day == day)
+    }
+
+    fn title(&self, day: Day) -> Option<&str> {
+        self.titles
+            .iter()
+            .find(|(d, _)| *d == day)
+            .map(|(_, title)| title.as_str())
+    }
+
+    /// Renders a full frame of `height` lines for a terminal that is `width` columns wide.
+    /// Lines end with `\r\n`, as a terminal in raw mode does not return the cursor on a newline.
+    pub fn render(&self, width: usize, height: usize) -> String {
+        let mut lines: Vec<String> = vec![
+            format!("{ANSI_BOLD}🎄 Advent of Code{ANSI_RESET}"),
+            String::new(),
+        ];
+
+        let days: Vec<Day> = all_days().collect();
+        for row in days.chunks(CALENDAR_COLUMNS as usize) {
+            let cells: Vec<String> = row.iter().map(|day| self.render_cell(*day)).collect();
+            lines.push(format!("  {}", cells.join(" ")));
+        }
+
+        lines.push(String::new());
+        lines.push(self.render_details());
+        lines.push(String::new());
+
+        let help = match self.confirm {
+            Some(action) => format!(
+                "{ANSI_BOLD}{} of day {}? (y/n){ANSI_RESET}",
+                action.label(),
+                self.selected
+            ),
+            None => format!(
+                "{ANSI_ITALIC}←↑↓→ select  s solve  t test  b benchmark  r read  1/2 submit  x cancel  q quit{ANSI_RESET}"
+            ),
+        };
+        lines.push(help);
+
+        let title = match self.running {
+            Some((action, day)) => format!(" {} day {day}… ", action.label()),
+            None if self.message.is_empty() => " output ".to_string(),
+            None => format!(" {} ", self.message),
+        };
+        lines.push(format!(
+            "──{title}{}",
+            "─".repeat(width.saturating_sub(title.chars().count() + 2))
+        ));
+
+        // the output pane fills the rest of the screen, showing the latest lines.
+        let pane = height.saturating_sub(lines.len());
+        let start = self.output.len().saturating_sub(pane);
+        lines.extend(
+            self.output[start..]
+                .iter()
+                .map(|line| line.chars().take(width).collect()),
+        );
+
+        let mut frame = String::from("\x1b[H");
+        for line in lines.iter().take(height) {
+            frame.push_str(line);
+            frame.push_str(CLEAR_LINE);
+            frame.push_str("\r\n");
+        }
+        // clear everything below the last line.
+        frame.push_str("\x1b[J");
+        frame
+    }
+
+    fn render_cell(&self, day: Day) -> String {
+        let part = |status: Option<&DayStatus>, i: usize| match status.map(|s| s.parts[i]) {
+            Some(PartStatus::Solved) => "★",
+            Some(PartStatus::Attempted) => "✖",
+            _ => "·",
+        };
+
+        let status = self.status(day);
+        let text = format!(" {day} {}{} ", part(status, 0), part(status, 1));
+
+        if day == self.selected {
+            format!("{ANSI_REVERSE}{text}{ANSI_RESET}")
+        } else if status.is_some_and(|s| s.scaffolded) {
+            format!("{ANSI_BOLD}{text}{ANSI_RESET}")
+        } else {
+            text
+        }
+    }
+
+    fn render_details(&self) -> String {
+        let day = self.selected;
+        let title = self
+            .title(day)
+            .map_or_else(|| "not downloaded".into(), ToString::to_string);
+
+        let Some(status) = self.status(day) else {
+            return format!("{ANSI_BOLD}Day {day}{ANSI_RESET}: {title}");
+        };
+
+        let check = |b: bool| if b { "✔" } else { "-" };
+        let mut details = format!(
+            "{ANSI_BOLD}Day {day}{ANSI_RESET}: {title}  code {}  input {}  examples {}  timed {}",
+            check(status.scaffolded),
+            check(status.input),
+            check(status.examples),
+            check(status.timed)
+        );
+        if !status.unfinished.is_empty() {
+            details.push_str(&format!("  {ANSI_ITALIC}todo!(){ANSI_RESET}"));
+        }
+        details
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_keys, Action, Dashboard, Key};
+    use crate::{day, template::markdown::strip_ansi};
+
+    #[test]
+    fn parses_keys() {
+        assert_eq!(
+            parse_keys(b"\x1b[A\x1b[Bq\r\x1bOC\x1b[D\x03"),
+            vec![
+                Key::Up,
+                Key::Down,
+                Key::Char('q'),
+                Key::Enter,
+                Key::Right,
+                Key::Left,
+                Key::Char('q')
+            ]
+        );
+        assert_eq!(Action::from_key(Key::Char('2')), Some(Action::Submit(2)));
+    }
+
+    #[test]
+    fn moves_through_the_calendar() {
+        let mut dashboard = Dashboard::new(day!(1), vec![], vec![]);
+
+        dashboard.move_selection(Key::Left);
+        assert_eq!(dashboard.selected, day!(1));
+
+        dashboard.move_selection(Key::Down);
+        dashboard.move_selection(Key::Right);
+        assert_eq!(dashboard.selected, day!(7));
+
+        dashboard.selected = day!(23);
+        dashboard.move_selection(Key::Down);
+        assert_eq!(dashboard.selected, day!(23));
+        dashboard.move_selection(Key::Up);
+        assert_eq!(dashboard.selected, day!(18));
+    }
+
+    #[test]
+    fn renders_latest_output() {
+        let mut dashboard = Dashboard::new(day!(3), vec![], vec![(day!(3), "Slices".into())]);
+        for i in 0..50 {
+            dashboard.push_output(&format!("line {i}"));
+        }
+        dashboard.push_output("Part 1: ✖\rPart 1: \x1b[1m42\x1b[0m (1.0ms)");
+
+        let frame = strip_ansi(&dashboard.render(60, 20));
+        let lines: Vec<&str> = frame.split("\r\n").collect();
+
+        assert!(frame.contains("Day 03: Slices"));
+        assert!(frame.contains("line 49"));
+        assert!(!frame.contains("line 10"));
+        assert_eq!(lines[lines.len() - 2], "Part 1: 42 (1.0ms)");
+        assert_eq!(lines.len() - 1, 20);
+    }
+
+    #[test]
+    fn builds_subcommand_args() {
+        assert_eq!(
+            Action::Submit(1).args(day!(13)),
+            vec!["solve", "13", "--submit", "1"]
+        );
+        assert_eq!(Action::Test.args(day!(5)), vec!["test-day", "05"]);
+    }
+}
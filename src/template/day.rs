@@ -3,9 +3,9 @@ use std::fmt::Display;
 use std::str::FromStr;
 
 #[cfg(feature = "today")]
-use chrono::{Datelike, FixedOffset, Utc};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, TimeZone, Utc};
 
-#[cfg(feature = "today")]
+/// Puzzles unlock at midnight in the timezone of the server.
 const SERVER_UTC_OFFSET: i32 = -5;
 
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
@@ -41,20 +41,63 @@ impl Day {
     pub fn into_inner(self) -> u8 {
         self.0
     }
+
+    /// Returns the unix timestamp at which the puzzle of this day unlocks in the given year.
+    pub fn unlock_timestamp(self, year: u16) -> i64 {
+        let days = days_from_civil(i64::from(year), 12, i64::from(self.0));
+        days * 86_400 - i64::from(SERVER_UTC_OFFSET) * 3600
+    }
+}
+
+/// Returns the number of days between the unix epoch and a date of the proleptic gregorian calendar.
+/// See: https://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
 }
 
 #[cfg(feature = "today")]
 impl Day {
     /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
     pub fn today() -> Option<Self> {
+        Self::today_at(Utc::now())
+    }
+
+    /// Returns the day whose puzzle is the latest one unlocked at `now`, if `now` is between the 1st and the 25th of december.
+    pub fn today_at(now: DateTime<Utc>) -> Option<Self> {
         let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
-        let today = Utc::now().with_timezone(&offset);
+        let today = now.with_timezone(&offset);
         if today.month() == 12 && today.day() <= 25 {
             Self::new(u8::try_from(today.day()).ok()?)
         } else {
             None
         }
     }
+
+    /// Returns the next puzzle to unlock after `now`, and the instant it unlocks.
+    /// After the 25th, this is the 1st of december of the next year.
+    pub fn next_unlock(now: DateTime<Utc>) -> Option<(Self, DateTime<Utc>)> {
+        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        let today = now.with_timezone(&offset);
+
+        let (year, day) = match (today.month(), today.day()) {
+            (12, d) if d < 25 => (today.year(), d + 1),
+            (12, _) => (today.year() + 1, 1),
+            _ => (today.year(), 1),
+        };
+
+        let midnight = NaiveDate::from_ymd_opt(year, 12, day)?.and_hms_opt(0, 0, 0)?;
+        let unlock = offset.from_local_datetime(&midnight).single()?;
+
+        Some((
+            Self::new(u8::try_from(day).ok()?)?,
+            unlock.with_timezone(&Utc),
+        ))
+    }
 }
 
 impl Display for Day {
@@ -152,10 +195,64 @@ macro_rules! day {
 
 /* -------------------------------------------------------------------------- */
 
+#[cfg(all(feature = "test_lib", feature = "today"))]
+mod today_tests {
+    use super::Day;
+    use chrono::{DateTime, Utc};
+
+    fn at(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn today_respects_server_timezone() {
+        assert_eq!(Day::today_at(at("2018-12-05T04:59:59Z")), Some(Day(4)));
+        assert_eq!(Day::today_at(at("2018-12-05T05:00:00Z")), Some(Day(5)));
+        assert_eq!(Day::today_at(at("2018-12-26T05:00:00Z")), None);
+        assert_eq!(Day::today_at(at("2018-11-30T12:00:00Z")), None);
+    }
+
+    #[test]
+    fn next_unlock_during_advent() {
+        assert_eq!(
+            Day::next_unlock(at("2018-12-05T04:55:00Z")),
+            Some((Day(5), at("2018-12-05T05:00:00Z")))
+        );
+        assert_eq!(
+            Day::next_unlock(at("2018-12-05T05:00:00Z")),
+            Some((Day(6), at("2018-12-06T05:00:00Z")))
+        );
+    }
+
+    #[test]
+    fn next_unlock_outside_advent() {
+        assert_eq!(
+            Day::next_unlock(at("2018-11-30T23:00:00Z")),
+            Some((Day(1), at("2018-12-01T05:00:00Z")))
+        );
+        assert_eq!(
+            Day::next_unlock(at("2018-12-25T06:00:00Z")),
+            Some((Day(1), at("2019-12-01T05:00:00Z")))
+        );
+        assert_eq!(
+            Day::next_unlock(at("2019-01-01T00:00:00Z")),
+            Some((Day(1), at("2019-12-01T05:00:00Z")))
+        );
+    }
+}
+
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{all_days, Day};
 
+    #[test]
+    fn unlock_timestamp() {
+        // 2018-12-01T05:00:00Z
+        assert_eq!(Day(1).unlock_timestamp(2018), 1_543_640_400);
+        // 2023-12-25T05:00:00Z
+        assert_eq!(Day(25).unlock_timestamp(2023), 1_703_480_400);
+    }
+
     #[test]
     fn all_days_iterator() {
         let mut iter = all_days();
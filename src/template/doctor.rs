@@ -0,0 +1,291 @@
+/// Module that evaluates the checks of `cargo doctor` and renders them with a fix for each problem.
+use std::fmt::Write as _;
+
+use crate::template::{readme_benchmarks, Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// The first year with an event.
+const FIRST_YEAR: u16 = 2015;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    Ok,
+    /// Something that only limits a few commands, e.g. `cargo time --store`.
+    Warning,
+    Problem,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Check {
+    /// The heading the check is listed under, e.g. `Tools`.
+    pub section: &'static str,
+    pub name: String,
+    pub severity: Severity,
+    pub detail: String,
+    /// What to do about a warning or problem.
+    pub fix: Option<String>,
+}
+
+impl Check {
+    pub fn ok(section: &'static str, name: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            section,
+            name: name.into(),
+            severity: Severity::Ok,
+            detail: detail.into(),
+            fix: None,
+        }
+    }
+
+    pub fn warning(
+        section: &'static str,
+        name: impl Into<String>,
+        detail: impl Into<String>,
+        fix: impl Into<String>,
+    ) -> Self {
+        Self {
+            severity: Severity::Warning,
+            fix: Some(fix.into()),
+            ..Self::ok(section, name, detail)
+        }
+    }
+
+    pub fn problem(
+        section: &'static str,
+        name: impl Into<String>,
+        detail: impl Into<String>,
+        fix: impl Into<String>,
+    ) -> Self {
+        Self {
+            severity: Severity::Problem,
+            fix: Some(fix.into()),
+            ..Self::ok(section, name, detail)
+        }
+    }
+}
+
+/// Checks the `AOC_YEAR` environment variable and the configured year against the years with an event.
+/// An `AOC_YEAR` that is not a number is ignored by the template, which is easy to miss.
+pub fn check_year(env: Option<&str>, configured: Option<u16>, current: u16) -> Check {
+    const SECTION: &str = "Configuration";
+    let valid = |year: u16| (FIRST_YEAR..=current).contains(&year);
+
+    if let Some(value) = env {
+        return match value.trim().parse::<u16>() {
+            Ok(year) if valid(year) => Check::ok(SECTION, "year", format!("{year} (AOC_YEAR)")),
+            _ => Check::problem(
+                SECTION,
+                "year",
+                format!("AOC_YEAR is set to \"{value}\", which is not an event year"),
+                format!("set AOC_YEAR to a year from {FIRST_YEAR} to {current}, or unset it"),
+            ),
+        };
+    }
+
+    match configured {
+        Some(year) if valid(year) => Check::ok(SECTION, "year", format!("{year} (aoc.json)")),
+        Some(year) => Check::problem(
+            SECTION,
+            "year",
+            format!("`year` in aoc.json is {year}, which is not an event year"),
+            format!("set `year` to a year from {FIRST_YEAR} to {current}"),
+        ),
+        None => Check::warning(
+            SECTION,
+            "year",
+            "not set, aoc-cli uses the latest event",
+            "set `year` in aoc.json or the AOC_YEAR environment variable",
+        ),
+    }
+}
+
+/// Checks that the readme has the markers that `cargo time --store` writes the benchmarks between.
+pub fn check_readme(path: &str, readme: Option<&str>) -> Check {
+    const SECTION: &str = "Readme";
+    let marker = readme_benchmarks::MARKER;
+
+    let Some(readme) = readme else {
+        return Check::warning(
+            SECTION,
+            path,
+            "not found, `cargo time --store` can not store benchmarks",
+            format!("create \"{path}\" or point `paths.readme` in aoc.json to your readme"),
+        );
+    };
+
+    match readme.matches(marker).count() {
+        0 => Check::warning(
+            SECTION,
+            path,
+            "benchmark marker is missing, `cargo time --store` will fail",
+            format!("add a line `{marker}` where the benchmarks should go"),
+        ),
+        1 | 2 => Check::ok(SECTION, path, "benchmark marker found"),
+        n => Check::problem(
+            SECTION,
+            path,
+            format!("benchmark marker appears {n} times"),
+            format!("keep two lines `{marker}` around the benchmarks table and remove the others"),
+        ),
+    }
+}
+
+/// Checks the data files of a scaffolded day. `None` means the file does not exist.
+pub fn check_day_files(day: Day, input: Option<&str>, example: Option<&str>) -> Vec<Check> {
+    const SECTION: &str = "Days";
+    let name = format!("day {day}");
+    let mut checks = vec![];
+
+    match input {
+        Some(input) if !input.trim().is_empty() => {}
+        Some(_) => checks.push(Check::problem(
+            SECTION,
+            &name,
+            "input is empty",
+            format!("run `cargo download {day}`"),
+        )),
+        None => checks.push(Check::problem(
+            SECTION,
+            &name,
+            "input is missing",
+            format!("run `cargo download {day}`"),
+        )),
+    }
+
+    match example {
+        Some(example) if !example.trim().is_empty() => {}
+        Some(_) => checks.push(Check::warning(
+            SECTION,
+            &name,
+            "example is empty, its tests will fail",
+            format!("run `cargo examples {day}` or paste the example from the puzzle"),
+        )),
+        None => checks.push(Check::warning(
+            SECTION,
+            &name,
+            "example is missing, its tests will fail",
+            format!("run `cargo examples {day}`"),
+        )),
+    }
+
+    if checks.is_empty() {
+        checks.push(Check::ok(SECTION, name, "input and example present"));
+    }
+
+    checks
+}
+
+/// Returns the year of a unix timestamp. Close enough to tell which events have started.
+#[allow(clippy::cast_possible_truncation)]
+pub fn year_of(unix_secs: u64) -> u16 {
+    // 365.2425 days per year on average.
+    (1970 + unix_secs / 31_556_952) as u16
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Renders the checks grouped by section, with the fix below each problem.
+pub fn render(checks: &[Check]) -> String {
+    let mut out = String::new();
+    let mut section = "";
+
+    for check in checks {
+        if check.section != section {
+            if !section.is_empty() {
+                out.push('\n');
+            }
+            section = check.section;
+            let _ = writeln!(out, "{ANSI_BOLD}{section}{ANSI_RESET}");
+        }
+
+        let symbol = match check.severity {
+            Severity::Ok => "✔",
+            Severity::Warning => "!",
+            Severity::Problem => "✖",
+        };
+        let _ = writeln!(out, "  {symbol} {}: {}", check.name, check.detail);
+
+        if let Some(fix) = &check.fix {
+            let _ = writeln!(out, "      {ANSI_ITALIC}→ {fix}{ANSI_RESET}");
+        }
+    }
+
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check_day_files, check_readme, check_year, render, year_of, Check, Severity};
+    use crate::{day, template::markdown::strip_ansi};
+
+    #[test]
+    fn checks_year() {
+        assert_eq!(check_year(Some("2018"), None, 2024).severity, Severity::Ok);
+        assert_eq!(
+            check_year(Some("18"), Some(2018), 2024).severity,
+            Severity::Problem
+        );
+        assert_eq!(
+            check_year(Some("next"), None, 2024).severity,
+            Severity::Problem
+        );
+        assert_eq!(
+            check_year(None, Some(2025), 2024).severity,
+            Severity::Problem
+        );
+        assert_eq!(check_year(None, None, 2024).severity, Severity::Warning);
+        assert_eq!(year_of(1_733_011_200), 2024);
+    }
+
+    #[test]
+    fn checks_readme_markers() {
+        let marker = "<!--- benchmarking table --->";
+        assert_eq!(check_readme("README.md", None).severity, Severity::Warning);
+        assert_eq!(
+            check_readme("README.md", Some("# readme")).severity,
+            Severity::Warning
+        );
+        assert_eq!(
+            check_readme("README.md", Some(&format!("{marker}\n{marker}"))).severity,
+            Severity::Ok
+        );
+        assert_eq!(
+            check_readme("README.md", Some(&marker.repeat(3))).severity,
+            Severity::Problem
+        );
+    }
+
+    #[test]
+    fn checks_day_files() {
+        let checks = check_day_files(day!(4), None, Some(" \n"));
+        assert_eq!(checks.len(), 2);
+        assert_eq!(checks[0].severity, Severity::Problem);
+        assert_eq!(checks[0].fix.as_deref(), Some("run `cargo download 04`"));
+        assert_eq!(checks[1].severity, Severity::Warning);
+
+        let checks = check_day_files(day!(4), Some("1"), Some("2"));
+        assert_eq!(
+            checks,
+            vec![Check::ok("Days", "day 04", "input and example present")]
+        );
+    }
+
+    #[test]
+    fn renders_fixes() {
+        let checks = [
+            Check::ok("Tools", "curl", "curl 8.5.0"),
+            Check::problem(
+                "Tools",
+                "aoc-cli",
+                "not found",
+                "run `cargo install aoc-cli`",
+            ),
+            Check::ok("Readme", "README.md", "benchmark marker found"),
+        ];
+        assert_eq!(
+            strip_ansi(&render(&checks)),
+            "Tools\n  ✔ curl: curl 8.5.0\n  ✖ aoc-cli: not found\n      → run `cargo install aoc-cli`\n\nReadme\n  ✔ README.md: benchmark marker found\n"
+        );
+    }
+}
@@ -0,0 +1,157 @@
+/// Module that defines the error returned by all commands and the exit code of each kind of error.
+use std::{fmt::Display, io};
+
+use crate::template::{
+    aoc_cli::AocCommandError, http::HttpError, readme_benchmarks, run_multi, Day,
+};
+
+/// An error of a command. Each kind of error exits with its own code, so that scripts can tell them apart:
+///
+/// | Code | Meaning                                                             |
+/// | ---- | ------------------------------------------------------------------- |
+/// | 0    | Success.                                                            |
+/// | 1    | The command ran, but did not succeed, e.g. some downloads failed.  |
+/// | 2    | Invalid arguments or configuration.                                 |
+/// | 3    | A file could not be read or written.                                |
+/// | 4    | aoc-cli is not installed or failed.                                 |
+/// | 5    | A request to the website failed.                                    |
+/// | 6    | A solution failed to build or run.                                  |
+#[derive(Debug)]
+pub enum Error {
+    Failed(String),
+    Usage(String),
+    Io {
+        context: String,
+        source: io::Error,
+    },
+    AocCli(AocCommandError),
+    Http(HttpError),
+    /// The days whose solution failed.
+    Solution(Vec<Day>),
+}
+
+impl Error {
+    pub fn io(context: impl Into<String>, source: io::Error) -> Self {
+        Error::Io {
+            context: context.into(),
+            source,
+        }
+    }
+
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::Failed(_) => 1,
+            Error::Usage(_) => 2,
+            Error::Io { .. } => 3,
+            Error::AocCli(_) => 4,
+            Error::Http(_) => 5,
+            Error::Solution(_) => 6,
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Failed(message) | Error::Usage(message) => f.write_str(message),
+            Error::Io { context, source } => write!(f, "{context}: {source}"),
+            Error::AocCli(AocCommandError::BadExitStatus(_)) => {
+                write!(f, "aoc-cli exited with a non-zero status.")
+            }
+            Error::AocCli(_) => write!(
+                f,
+                "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+            ),
+            Error::Http(e) => write!(f, "Request failed: {e}"),
+            Error::Solution(days) => {
+                let days: Vec<String> = days.iter().map(ToString::to_string).collect();
+                write!(
+                    f,
+                    "The solution of day {} failed to build or run.",
+                    days.join(", ")
+                )
+            }
+        }
+    }
+}
+
+impl From<AocCommandError> for Error {
+    fn from(e: AocCommandError) -> Self {
+        match e {
+            AocCommandError::UnknownAccount(_) => Error::Usage(e.to_string()),
+            e => Error::AocCli(e),
+        }
+    }
+}
+
+impl From<HttpError> for Error {
+    fn from(e: HttpError) -> Self {
+        Error::Http(e)
+    }
+}
+
+impl From<run_multi::Error> for Error {
+    fn from(e: run_multi::Error) -> Self {
+        match e {
+            run_multi::Error::BrokenPipe => Error::Failed("Lost the output of a solution.".into()),
+            run_multi::Error::IO(e) => Error::io("Failed to run cargo", e),
+        }
+    }
+}
+
+impl From<readme_benchmarks::Error> for Error {
+    fn from(e: readme_benchmarks::Error) -> Self {
+        match e {
+            readme_benchmarks::Error::Parser(e) => {
+                Error::Failed(format!("Failed to store updated benchmarks: {e}"))
+            }
+            readme_benchmarks::Error::IO(e) => Error::io("Failed to store updated benchmarks", e),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Error;
+    use crate::{day, template::aoc_cli::AocCommandError};
+
+    #[test]
+    fn exit_codes_are_distinct() {
+        let errors = [
+            Error::Failed("failed".into()),
+            Error::Usage("usage".into()),
+            Error::io("read", std::io::ErrorKind::NotFound.into()),
+            Error::AocCli(AocCommandError::CommandNotFound),
+            Error::Http(crate::template::http::HttpError::CommandNotCallable),
+            Error::Solution(vec![day!(1)]),
+        ];
+
+        let mut codes: Vec<i32> = errors.iter().map(Error::exit_code).collect();
+        assert!(!codes.contains(&0));
+        codes.dedup();
+        assert_eq!(codes.len(), errors.len());
+    }
+
+    #[test]
+    fn formats_messages() {
+        let e = Error::io(
+            "Failed to create module file",
+            std::io::ErrorKind::AlreadyExists.into(),
+        );
+        assert_eq!(
+            e.to_string(),
+            "Failed to create module file: entity already exists"
+        );
+        assert!(Error::AocCli(AocCommandError::CommandNotFound)
+            .to_string()
+            .contains("cargo install aoc-cli"));
+
+        let e = Error::from(AocCommandError::UnknownAccount("bob".into()));
+        assert_eq!(e.exit_code(), 2);
+        assert!(e.to_string().contains("cargo account add bob"));
+    }
+}
@@ -0,0 +1,171 @@
+/// Module that parses the global stats page of an event, i.e. how many people solved each day.
+use std::{fmt::Write as _, time::Duration};
+
+use crate::template::{
+    leaderboard::format_duration, stats::Stats, timings::Timings, Day, ANSI_BOLD, ANSI_ITALIC,
+    ANSI_RESET,
+};
+
+/// The number of people that solved a day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventDay {
+    pub day: Day,
+    /// People with both stars.
+    pub both: u32,
+    /// People with only the first star.
+    pub first_only: u32,
+}
+
+impl EventDay {
+    /// Share of the people that started the day who also finished part two.
+    pub fn completion_rate(&self) -> f64 {
+        let total = self.both + self.first_only;
+        if total == 0 {
+            0.0
+        } else {
+            f64::from(self.both) / f64::from(total)
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct EventStats {
+    pub days: Vec<EventDay>,
+}
+
+impl EventStats {
+    pub fn get(&self, day: Day) -> Option<&EventDay> {
+        self.days.iter().find(|d| d.day == day)
+    }
+
+    /// Ranks days by the number of people with both stars: 1 is the day the fewest finished.
+    pub fn difficulty_rank(&self, day: Day) -> Option<usize> {
+        let both = self.get(day)?.both;
+        Some(self.days.iter().filter(|d| d.both < both).count() + 1)
+    }
+}
+
+impl TryFrom<String> for EventStats {
+    type Error = String;
+
+    /// Parses the html of `/{year}/stats`. Every day is a link of the form
+    /// `<a href="/2018/day/1">1 <span class="stats-both">123</span> <span class="stats-firstonly">45</span>...</a>`.
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let mut days: Vec<EventDay> = vec![];
+
+        for link in value.split("<a href=\"/").skip(1) {
+            let Some(day) = link
+                .split_once("/day/")
+                .and_then(|(_, rest)| rest.split('"').next())
+                .and_then(|day| day.parse::<u8>().ok())
+                .and_then(Day::new)
+            else {
+                continue;
+            };
+
+            let count = |class: &str| {
+                link.split_once(&format!("class=\"{class}\">"))
+                    .and_then(|(_, rest)| rest.split('<').next())
+                    .and_then(|n| n.trim().parse::<u32>().ok())
+            };
+
+            if let (Some(both), Some(first_only)) = (count("stats-both"), count("stats-firstonly"))
+            {
+                days.push(EventDay {
+                    day,
+                    both,
+                    first_only,
+                });
+            }
+        }
+
+        if days.is_empty() {
+            return Err("no days found, expected the html of the stats page.".into());
+        }
+
+        days.sort_unstable_by_key(|d| d.day);
+        days.dedup_by_key(|d| d.day);
+        Ok(EventStats { days })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Renders per day how many people got both stars versus only one, next to the own solve time and runtime.
+pub fn render(event: &EventStats, stats: &Stats, timings: &Timings) -> String {
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "{:<6}  {:>8}  {:>8}  {:>6}  {:>4}  {:>8}  {:>10}",
+        "", "Both", "First", "Done", "Rank", "Ours", "Runtime"
+    );
+
+    for event_day in &event.days {
+        let day = event_day.day;
+
+        let time = stats
+            .get(day)
+            .and_then(|d| d.time_to_solve(2).or_else(|| d.time_to_solve(1)))
+            .map_or_else(|| "-".into(), format_duration);
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let runtime = timings.data.iter().find(|t| t.day == day).map_or_else(
+            || "-".into(),
+            |t| format!("{:.1?}", Duration::from_nanos(t.total_nanos as u64)),
+        );
+
+        let _ = writeln!(
+            out,
+            "Day {day}  {ANSI_BOLD}{:>8}{ANSI_RESET}  {:>8}  {:>5.1}%  {:>4}  {ANSI_ITALIC}{:>8}{ANSI_RESET}  {:>10}",
+            event_day.both,
+            event_day.first_only,
+            event_day.completion_rate() * 100.0,
+            event.difficulty_rank(day).unwrap_or_default(),
+            time,
+            runtime,
+        );
+    }
+
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::EventStats;
+    use crate::day;
+
+    const FIXTURE: &str = r#"<main>
+<pre class="stats"><a href="/2018/day/2"> 2 <span class="stats-both"> 80</span> <span class="stats-firstonly">  20</span> <span class="stats-both">***</span><span class="stats-firstonly">*</span></a>
+<a href="/2018/day/1"> 1 <span class="stats-both">  90</span> <span class="stats-firstonly">  10</span> <span class="stats-both">****</span></a>
+<a href="/2018/day/3"> 3 <span class="stats-both">  80</span> <span class="stats-firstonly">  40</span></a>
+</pre>
+<a href="/2018/about">[About]</a>
+</main>"#;
+
+    #[test]
+    fn parses_stats_page() {
+        let stats = EventStats::try_from(FIXTURE.to_string()).unwrap();
+        assert_eq!(stats.days.len(), 3);
+        assert_eq!(stats.days[0].day, day!(1));
+        assert_eq!(stats.days[0].both, 90);
+        assert_eq!(stats.days[0].first_only, 10);
+        assert!((stats.days[1].completion_rate() - 0.8).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn ranks_difficulty() {
+        let stats = EventStats::try_from(FIXTURE.to_string()).unwrap();
+        assert_eq!(stats.difficulty_rank(day!(2)), Some(1));
+        assert_eq!(stats.difficulty_rank(day!(3)), Some(1));
+        assert_eq!(stats.difficulty_rank(day!(1)), Some(3));
+        assert_eq!(stats.difficulty_rank(day!(4)), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_other_pages() {
+        EventStats::try_from("<html>Not found</html>".to_string()).unwrap();
+    }
+}
@@ -0,0 +1,297 @@
+use std::{collections::HashMap, fs, io::Error, path::Path, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{config, Day};
+
+fn get_file_path() -> String {
+    config::get().example_manifest_path()
+}
+
+/// Represents the examples extracted from the puzzle description of a single day.
+#[derive(Clone, Debug)]
+pub struct ExampleEntry {
+    pub day: Day,
+    pub files: Vec<String>,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl ExampleEntry {
+    /// Returns the expected example answer of a part.
+    pub fn answer(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Represents the extracted examples for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct ExampleManifest {
+    pub data: Vec<ExampleEntry>,
+}
+
+impl ExampleManifest {
+    /// Dehydrate the manifest to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        config::store_json(&get_file_path(), &JsonValue::from(self.clone()))
+    }
+
+    /// Rehydrate the manifest from a JSON file. If not present, returns an empty manifest.
+    pub fn read_from_file() -> Self {
+        let path = get_file_path();
+        if !Path::new(&path).exists() {
+            return ExampleManifest::default();
+        }
+
+        let s = fs::read_to_string(&path)
+            .map_err(|x| x.to_string())
+            .and_then(ExampleManifest::try_from);
+
+        match s {
+            Ok(manifest) => manifest,
+            Err(e) => {
+                eprintln!("{e}");
+                ExampleManifest::default()
+            }
+        }
+    }
+
+    pub fn get(&self, day: Day) -> Option<&ExampleEntry> {
+        self.data.iter().find(|e| e.day == day)
+    }
+
+    /// Inserts an entry, merging it into an existing entry for the same day.
+    /// Known answers replace stored ones, but an answer that was picked before is kept
+    /// if it is unknown now, e.g. when the puzzle is refreshed without asking.
+    pub fn upsert(&mut self, entry: ExampleEntry) {
+        match self.data.iter_mut().find(|e| e.day == entry.day) {
+            Some(existing) => {
+                for file in entry.files {
+                    if !existing.files.contains(&file) {
+                        existing.files.push(file);
+                    }
+                }
+                existing.part_1 = entry.part_1.or(existing.part_1.take());
+                existing.part_2 = entry.part_2.or(existing.part_2.take());
+            }
+            None => {
+                self.data.push(entry);
+                self.data.sort_unstable_by_key(|e| e.day);
+            }
+        }
+    }
+}
+
+/// Names the example files of a day after the part of each code block, so that
+/// `read_file_part("examples", DAY, 2)` reads the first example of part two.
+/// Further blocks of a part get an index, e.g. `13-1.txt`, `13-1-2.txt` and `13-2.txt`.
+pub fn example_file_names(day: Day, parts: &[u8]) -> Vec<String> {
+    let mut seen: HashMap<u8, usize> = HashMap::new();
+
+    parts
+        .iter()
+        .map(|&part| {
+            let count = seen.entry(part).or_default();
+            *count += 1;
+            match *count {
+                1 => format!("{day}-{part}.txt"),
+                n => format!("{day}-{part}-{n}.txt"),
+            }
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<ExampleManifest> for JsonValue {
+    fn from(value: ExampleManifest) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for ExampleManifest {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(ExampleManifest {
+            data: json_data
+                .iter()
+                .map(ExampleEntry::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&ExampleEntry> for JsonValue {
+    fn from(value: &ExampleEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert(
+            "files".into(),
+            JsonValue::Array(
+                value
+                    .files
+                    .iter()
+                    .map(|f| JsonValue::String(f.clone()))
+                    .collect(),
+            ),
+        );
+
+        for (key, answer) in [("part_1", &value.part_1), ("part_2", &value.part_2)] {
+            map.insert(
+                key.into(),
+                answer.clone().map_or(JsonValue::Null, JsonValue::String),
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for ExampleEntry {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected example entry to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected example.day to be a Day struct.")?;
+
+        let files = json
+            .get("files")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .and_then(|files| {
+                files
+                    .iter()
+                    .map(|f| f.get::<String>().cloned())
+                    .collect::<Option<Vec<_>>>()
+            })
+            .ok_or("Expected example.files to be an array of strings.")?;
+
+        let part_1 = json
+            .get("part_1")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected example.part_1 to be null or string.")?;
+
+        let part_2 = json
+            .get("part_2")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected example.part_2 to be null or string.")?;
+
+        Ok(ExampleEntry {
+            day,
+            files,
+            part_1: part_1.cloned(),
+            part_2: part_2.cloned(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{example_file_names, ExampleEntry, ExampleManifest};
+    use crate::day;
+
+    #[test]
+    fn roundtrips_json() {
+        let mut manifest = ExampleManifest::default();
+        manifest.upsert(ExampleEntry {
+            day: day!(13),
+            files: vec!["13-1.txt".into(), "13-2.txt".into()],
+            part_1: Some("7,3".into()),
+            part_2: None,
+        });
+
+        let json = tinyjson::JsonValue::from(manifest).stringify().unwrap();
+        let parsed = ExampleManifest::try_from(json).unwrap();
+        let entry = parsed.get(day!(13)).unwrap();
+        assert_eq!(entry.files.len(), 2);
+        assert_eq!(entry.answer(1), Some("7,3"));
+        assert_eq!(entry.answer(2), None);
+    }
+
+    #[test]
+    fn names_files_after_parts() {
+        assert_eq!(
+            example_file_names(day!(13), &[1, 1, 2, 1, 2]),
+            vec![
+                "13-1.txt",
+                "13-1-2.txt",
+                "13-2.txt",
+                "13-1-3.txt",
+                "13-2-2.txt"
+            ]
+        );
+        assert_eq!(example_file_names(day!(4), &[2]), vec!["04-2.txt"]);
+    }
+
+    #[test]
+    fn upsert_replaces_existing_day() {
+        let mut manifest = ExampleManifest::default();
+        for answer in ["1", "2"] {
+            manifest.upsert(ExampleEntry {
+                day: day!(1),
+                files: vec![],
+                part_1: Some(answer.into()),
+                part_2: None,
+            });
+        }
+        assert_eq!(manifest.data.len(), 1);
+        assert_eq!(manifest.data[0].answer(1), Some("2"));
+    }
+
+    #[test]
+    fn upsert_keeps_picked_answers_on_refresh() {
+        let mut manifest = ExampleManifest::default();
+        // picked interactively in `cargo examples`.
+        manifest.upsert(ExampleEntry {
+            day: day!(13),
+            files: vec!["13-1.txt".into()],
+            part_1: Some("7,3".into()),
+            part_2: None,
+        });
+        // refreshed after part one was accepted, the ambiguous part one is not asked for again.
+        manifest.upsert(ExampleEntry {
+            day: day!(13),
+            files: vec!["13-1.txt".into(), "13-2.txt".into()],
+            part_1: None,
+            part_2: Some("6,4".into()),
+        });
+
+        let entry = manifest.get(day!(13)).unwrap();
+        assert_eq!(entry.answer(1), Some("7,3"));
+        assert_eq!(entry.answer(2), Some("6,4"));
+        assert_eq!(entry.files, vec!["13-1.txt", "13-2.txt"]);
+    }
+}
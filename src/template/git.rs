@@ -0,0 +1,188 @@
+/// Wrapper module around the "git" command-line, used to commit solutions and benchmarks automatically.
+/// Inputs, examples and puzzle descriptions are never committed, as they must not be shared publicly.
+use std::{
+    fmt::Display,
+    path::Path,
+    process::{Command, Output},
+};
+
+use crate::template::{config, Day};
+
+#[derive(Debug)]
+pub enum GitError {
+    CommandNotCallable,
+    BadExitStatus(Output),
+    /// Files that are staged, but not part of the commit.
+    UnrelatedStaged(Vec<String>),
+    /// A file from the inputs, examples or puzzles folder.
+    DataFile(String),
+}
+
+impl Display for GitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GitError::CommandNotCallable => write!(f, "git could not be called."),
+            GitError::BadExitStatus(output) => write!(
+                f,
+                "git failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+            GitError::UnrelatedStaged(paths) => write!(
+                f,
+                "other files are staged ({}), commit or unstage them first.",
+                paths.join(", ")
+            ),
+            GitError::DataFile(path) => {
+                write!(f, "\"{path}\" holds puzzle data and is never committed.")
+            }
+        }
+    }
+}
+
+/// Commits the solution of a day after a part was accepted, together with the solve statistics.
+pub fn commit_solution(day: Day, part: u8) {
+    let config = config::get();
+    let paths = [config.bin_path(day), config.account_data_file("stats.json")];
+    report(commit(&paths, &solution_message(day, part)));
+}
+
+/// Commits the stored timings and the readme with the benchmarks table.
+pub fn commit_benchmarks() {
+    let config = config::get();
+    let paths = [
+        config.data_file("timings.json"),
+        config.paths.readme.clone(),
+    ];
+    report(commit(&paths, "Update benchmarks"));
+}
+
+pub fn solution_message(day: Day, part: u8) -> String {
+    format!("Day {day} part {part} solved")
+}
+
+/// The commit is a convenience, so a failure is reported without failing the command.
+fn report(result: Result<bool, GitError>) {
+    match result {
+        Ok(true) => {}
+        Ok(false) => println!("Nothing to commit."),
+        Err(e) => eprintln!("Did not commit: {e}"),
+    }
+}
+
+/// Stages the paths that exist and are not ignored, then commits them.
+/// Returns `false` if none of them changed.
+fn commit(paths: &[String], message: &str) -> Result<bool, GitError> {
+    let config = config::get();
+    // the whole folders, including those of other accounts.
+    let data_folders = [
+        &config.paths.inputs,
+        &config.paths.examples,
+        &config.paths.puzzles,
+    ];
+
+    if let Some(path) = paths.iter().find(|p| is_data_path(p, &data_folders)) {
+        return Err(GitError::DataFile(path.clone()));
+    }
+
+    let prefix = git(&["rev-parse", "--show-prefix"])?;
+    let staged = git(&["diff", "--cached", "--name-only", "--no-renames"])?;
+    let staged: Vec<&str> = staged.lines().collect();
+
+    let unrelated = unrelated_staged(&staged, paths, prefix.trim());
+    if !unrelated.is_empty() {
+        return Err(GitError::UnrelatedStaged(unrelated));
+    }
+
+    let paths: Vec<&str> = paths
+        .iter()
+        .map(String::as_str)
+        .filter(|p| Path::new(p).exists() && git(&["check-ignore", "--quiet", p]).is_err())
+        .collect();
+    if paths.is_empty() {
+        return Ok(false);
+    }
+
+    let mut add = vec!["add", "--"];
+    add.extend(&paths);
+    git(&add)?;
+
+    // `diff --quiet` fails if there are changes.
+    if git(&["diff", "--cached", "--quiet"]).is_ok() {
+        return Ok(false);
+    }
+
+    git(&["commit", "--quiet", "-m", message])?;
+    println!("🎄 Committed \"{message}\".");
+    Ok(true)
+}
+
+fn git(args: &[&str]) -> Result<String, GitError> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .map_err(|_| GitError::CommandNotCallable)?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        Err(GitError::BadExitStatus(output))
+    }
+}
+
+/// Whether a path lies in one of the data folders.
+pub fn is_data_path(path: &str, data_folders: &[impl AsRef<Path>]) -> bool {
+    let path = Path::new(path.trim_start_matches("./"));
+    data_folders.iter().any(|folder| path.starts_with(folder))
+}
+
+/// Returns the staged files that are not among `paths`.
+/// Staged files are relative to the repository root, `paths` to the working directory at `prefix`.
+pub fn unrelated_staged(staged: &[&str], paths: &[String], prefix: &str) -> Vec<String> {
+    let paths: Vec<String> = paths
+        .iter()
+        .map(|p| format!("{prefix}{}", p.trim_start_matches("./")))
+        .collect();
+
+    staged
+        .iter()
+        .filter(|s| !paths.iter().any(|p| p == *s))
+        .map(ToString::to_string)
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{is_data_path, solution_message, unrelated_staged};
+    use crate::day;
+
+    #[test]
+    fn finds_unrelated_staged_files() {
+        let paths = vec!["src/bin/13.rs".to_string(), "./data/stats.json".to_string()];
+
+        assert!(unrelated_staged(&["src/bin/13.rs", "data/stats.json"], &paths, "").is_empty());
+        assert_eq!(
+            unrelated_staged(&["src/bin/13.rs", "src/lib.rs"], &paths, ""),
+            vec!["src/lib.rs"]
+        );
+        assert_eq!(
+            unrelated_staged(&["aoc/src/bin/13.rs", "src/bin/13.rs"], &paths, "aoc/"),
+            vec!["src/bin/13.rs"]
+        );
+    }
+
+    #[test]
+    fn detects_data_paths() {
+        let folders = ["data/inputs", "data/examples", "data/puzzles"];
+        assert!(is_data_path("data/inputs/13.txt", &folders));
+        assert!(is_data_path("./data/puzzles/13.md", &folders));
+        assert!(!is_data_path("data/timings.json", &folders));
+        assert!(!is_data_path("data/inputs-old.txt", &folders));
+    }
+
+    #[test]
+    fn formats_messages() {
+        assert_eq!(solution_message(day!(13), 2), "Day 13 part 2 solved");
+    }
+}
@@ -0,0 +1,167 @@
+/// Module that runs the hooks configured in `aoc.json` around template actions.
+/// A hook is a shell command. It gets the day, part, answer and outcome of the action as environment variables.
+use std::process::{Command, ExitStatus};
+
+use crate::template::{config, Day, ANSI_ITALIC, ANSI_RESET};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hook {
+    PostScaffold,
+    PreSolve,
+    PostSolve,
+    /// After aoc-cli submitted an answer, whatever the verdict.
+    PostSubmit,
+    /// After `cargo time --store` stored the benchmarks.
+    PostStore,
+}
+
+impl Hook {
+    pub fn name(self) -> &'static str {
+        match self {
+            Hook::PostScaffold => "post_scaffold",
+            Hook::PreSolve => "pre_solve",
+            Hook::PostSolve => "post_solve",
+            Hook::PostSubmit => "post_submit",
+            Hook::PostStore => "post_store",
+        }
+    }
+
+    fn command(self, hooks: &config::Hooks) -> Option<&str> {
+        match self {
+            Hook::PostScaffold => hooks.post_scaffold.as_deref(),
+            Hook::PreSolve => hooks.pre_solve.as_deref(),
+            Hook::PostSolve => hooks.post_solve.as_deref(),
+            Hook::PostSubmit => hooks.post_submit.as_deref(),
+            Hook::PostStore => hooks.post_store.as_deref(),
+        }
+    }
+}
+
+/// What a hook is told about the action it runs for. Unset fields are not passed.
+#[derive(Clone, Debug, Default)]
+pub struct Context {
+    pub days: Vec<Day>,
+    pub part: Option<u8>,
+    pub answer: Option<String>,
+    /// E.g. `success` for a solution, or the verdict of a submission like `correct`.
+    pub outcome: Option<String>,
+}
+
+impl Context {
+    pub fn day(day: Day) -> Self {
+        Self {
+            days: vec![day],
+            ..Self::default()
+        }
+    }
+
+    /// The environment variables of the hook. Several days are separated by spaces.
+    pub fn env(&self) -> Vec<(&'static str, String)> {
+        let mut env = vec![];
+
+        if !self.days.is_empty() {
+            let days: Vec<String> = self.days.iter().map(ToString::to_string).collect();
+            env.push(("AOC_DAY", days.join(" ")));
+        }
+        if let Some(part) = self.part {
+            env.push(("AOC_PART", part.to_string()));
+        }
+        if let Some(answer) = &self.answer {
+            env.push(("AOC_ANSWER", answer.clone()));
+        }
+        if let Some(outcome) = &self.outcome {
+            env.push(("AOC_OUTCOME", outcome.clone()));
+        }
+
+        env
+    }
+}
+
+/// Runs a hook if it is configured. A failing hook is reported, but does not fail the action:
+/// the hook runs after the template stored its state, so there is nothing to roll back.
+pub fn run(hook: Hook, context: &Context) {
+    let Some(command) = hook.command(&config::get().hooks) else {
+        return;
+    };
+
+    match shell(command).envs(context.env()).status() {
+        Ok(status) if status.success() => {}
+        Ok(status) => eprintln!(
+            "{ANSI_ITALIC}Hook `{}` failed: {}{ANSI_RESET}",
+            hook.name(),
+            describe(status)
+        ),
+        Err(e) => eprintln!(
+            "{ANSI_ITALIC}Hook `{}` could not be run: {e}{ANSI_RESET}",
+            hook.name()
+        ),
+    }
+}
+
+fn shell(command: &str) -> Command {
+    if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", command]);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", command]);
+        cmd
+    }
+}
+
+fn describe(status: ExitStatus) -> String {
+    match status.code() {
+        Some(code) => format!("exited with code {code}"),
+        None => "was stopped".into(),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{shell, Context};
+    use crate::day;
+
+    #[test]
+    fn builds_environment() {
+        assert!(Context::default().env().is_empty());
+
+        let context = Context {
+            part: Some(2),
+            answer: Some("1337".into()),
+            outcome: Some("correct".into()),
+            ..Context::day(day!(7))
+        };
+        assert_eq!(
+            context.env(),
+            vec![
+                ("AOC_DAY", "07".to_string()),
+                ("AOC_PART", "2".to_string()),
+                ("AOC_ANSWER", "1337".to_string()),
+                ("AOC_OUTCOME", "correct".to_string())
+            ]
+        );
+
+        let context = Context {
+            days: vec![day!(1), day!(12)],
+            ..Context::default()
+        };
+        assert_eq!(context.env(), vec![("AOC_DAY", "01 12".to_string())]);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn passes_environment_to_the_shell() {
+        let context = Context {
+            outcome: Some("success".into()),
+            ..Context::day(day!(3))
+        };
+        let output = shell("echo \"$AOC_DAY $AOC_OUTCOME\"")
+            .envs(context.env())
+            .output()
+            .unwrap();
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "03 success\n");
+    }
+}
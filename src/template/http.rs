@@ -0,0 +1,78 @@
+/// Wrapper module around the "curl" command-line, used for requests that aoc-cli does not cover.
+use std::{
+    fmt::Display,
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+#[derive(Debug)]
+pub enum HttpError {
+    CommandNotCallable,
+    BadExitStatus(Output),
+}
+
+impl Display for HttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HttpError::CommandNotCallable => write!(f, "curl could not be called."),
+            HttpError::BadExitStatus(output) => {
+                write!(
+                    f,
+                    "request failed: {}",
+                    String::from_utf8_lossy(&output.stderr).trim()
+                )
+            }
+        }
+    }
+}
+
+/// Fetches a document, authenticated with the session token if one is passed.
+/// Fails for HTTP error statuses.
+pub fn get(url: &str, session: Option<&str>) -> Result<String, HttpError> {
+    let mut cmd = Command::new("curl");
+    cmd.args(["--silent", "--show-error", "--fail", "--location"]);
+
+    // the token is written to stdin, arguments can be read by other users of the machine.
+    if session.is_some() {
+        cmd.args(["--header", "@-"]);
+    }
+
+    let mut child = cmd
+        .arg(url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|_| HttpError::CommandNotCallable)?;
+
+    if let (Some(session), Some(mut stdin)) = (session, child.stdin.take()) {
+        // if curl exits before reading the header, the request fails and reports why.
+        let _ = writeln!(stdin, "{}", cookie_header(session));
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|_| HttpError::CommandNotCallable)?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        Err(HttpError::BadExitStatus(output))
+    }
+}
+
+fn cookie_header(session: &str) -> String {
+    format!("Cookie: session={}", session.trim())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::cookie_header;
+
+    #[test]
+    fn formats_the_cookie_header() {
+        assert_eq!(cookie_header("abc123\n"), "Cookie: session=abc123");
+    }
+}
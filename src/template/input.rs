@@ -0,0 +1,168 @@
+/// Module that determines which input a solution runs against: the puzzle input, an example, a file or stdin.
+use std::{
+    error::Error,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::template::{config, Day};
+
+/// The input a solution reads, selected with `--input <path>`, `--example [N]` or `--stdin`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The downloaded puzzle input of the day.
+    #[default]
+    Puzzle,
+    /// `data/examples/DD.txt`, or `data/examples/DD-N.txt` if a number is given.
+    Example(Option<u8>),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Parses the arguments passed to a solution binary. Unrelated arguments like `--submit 1` are ignored.
+    pub fn from_args(args: &[String]) -> Result<Self, InputSourceError> {
+        let mut sources = vec![];
+
+        for (i, arg) in args.iter().enumerate() {
+            let next = args.get(i + 1);
+            match arg.as_str() {
+                "--stdin" => sources.push(InputSource::Stdin),
+                "--input" => match next.filter(|n| !n.starts_with("--")) {
+                    Some(path) => sources.push(InputSource::File(path.into())),
+                    None => return Err(InputSourceError::MissingPath),
+                },
+                "--example" => {
+                    let n = next.and_then(|n| n.parse::<u8>().ok());
+                    sources.push(InputSource::Example(n));
+                }
+                _ => {}
+            }
+        }
+
+        match sources.len() {
+            0 => Ok(InputSource::Puzzle),
+            1 => Ok(sources.remove(0)),
+            _ => Err(InputSourceError::Ambiguous),
+        }
+    }
+
+    /// The arguments that select this source when passed to a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            InputSource::Puzzle => vec![],
+            InputSource::Example(None) => vec!["--example".into()],
+            InputSource::Example(Some(n)) => vec!["--example".into(), n.to_string()],
+            InputSource::File(path) => vec!["--input".into(), path.display().to_string()],
+            InputSource::Stdin => vec!["--stdin".into()],
+        }
+    }
+
+    /// The file this source reads from, if any.
+    pub fn path(&self, day: Day) -> Option<PathBuf> {
+        let config = config::get();
+        match self {
+            InputSource::Puzzle => Some(config.input_path(day).into()),
+            InputSource::Example(None) => Some(config.example_path(day).into()),
+            InputSource::Example(Some(n)) => {
+                Some(Path::new(&config.paths.examples).join(format!("{day}-{n}.txt")))
+            }
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+        }
+    }
+
+    pub fn read(&self, day: Day) -> io::Result<String> {
+        match self.path(day) {
+            Some(path) => fs::read_to_string(path),
+            None => io::read_to_string(io::stdin()),
+        }
+    }
+
+    /// Describes the source for the output of the runner, e.g. `example data/examples/01-2.txt`.
+    pub fn describe(&self, day: Day) -> String {
+        match (self, self.path(day)) {
+            (InputSource::Example(_), Some(path)) => format!("example {}", path.display()),
+            (_, Some(path)) => path.display().to_string(),
+            (_, None) => "stdin".into(),
+        }
+    }
+}
+
+/// An error which can be returned when parsing an [`InputSource`].
+#[derive(Debug)]
+pub enum InputSourceError {
+    MissingPath,
+    Ambiguous,
+}
+
+impl Error for InputSourceError {}
+
+impl Display for InputSourceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSourceError::MissingPath => f.write_str("`--input` expects a path"),
+            InputSourceError::Ambiguous => {
+                f.write_str("only one of `--input`, `--example` and `--stdin` can be used")
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::InputSource;
+
+    fn parse(args: &[&str]) -> Option<InputSource> {
+        let args: Vec<String> = args.iter().map(ToString::to_string).collect();
+        InputSource::from_args(&args).ok()
+    }
+
+    #[test]
+    fn parses_sources() {
+        assert_eq!(parse(&["target/debug/01"]), Some(InputSource::Puzzle));
+        assert_eq!(parse(&["01", "--submit", "1"]), Some(InputSource::Puzzle));
+        assert_eq!(parse(&["01", "--stdin"]), Some(InputSource::Stdin));
+        assert_eq!(
+            parse(&["01", "--example"]),
+            Some(InputSource::Example(None))
+        );
+        assert_eq!(
+            parse(&["01", "--example", "2", "--time"]),
+            Some(InputSource::Example(Some(2)))
+        );
+        assert_eq!(
+            parse(&["01", "--example", "--time"]),
+            Some(InputSource::Example(None))
+        );
+        assert_eq!(
+            parse(&["01", "--input", "friend.txt"]),
+            Some(InputSource::File("friend.txt".into()))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_sources() {
+        assert_eq!(parse(&["01", "--input"]), None);
+        assert_eq!(parse(&["01", "--input", "--time"]), None);
+        assert_eq!(parse(&["01", "--stdin", "--example"]), None);
+    }
+
+    #[test]
+    fn round_trips_args() {
+        for source in [
+            InputSource::Puzzle,
+            InputSource::Stdin,
+            InputSource::Example(None),
+            InputSource::Example(Some(3)),
+            InputSource::File("a/b.txt".into()),
+        ] {
+            let mut args = vec!["01".to_string()];
+            args.extend(source.to_args());
+            assert_eq!(InputSource::from_args(&args).unwrap(), source);
+        }
+    }
+}
@@ -0,0 +1,155 @@
+/// Module that guesses the shape of a puzzle input, so that `cargo scaffold` can start from a template
+/// that already parses it. Inputs that fit none of the shapes use the default template.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Shape {
+    /// A single number, e.g. a serial number or a recipe count.
+    Integer,
+    /// Lines of the same width, e.g. a map of tracks or a cave.
+    Grid,
+    /// Lines that each hold the same number of integers.
+    Records,
+    /// Groups of lines separated by blank lines.
+    Blocks,
+}
+
+impl Shape {
+    /// The name of the template that is scaffolded for the shape.
+    pub fn template_name(self) -> &'static str {
+        match self {
+            Shape::Integer => "integer",
+            Shape::Grid => "grid",
+            Shape::Records => "records",
+            Shape::Blocks => "blocks",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Shape::Integer => "a single integer",
+            Shape::Grid => "a character grid",
+            Shape::Records => "numeric records",
+            Shape::Blocks => "blocks separated by blank lines",
+        }
+    }
+}
+
+/// Detects the shape of an input. The shapes are tried from the most to the least specific.
+pub fn detect(input: &str) -> Option<Shape> {
+    // trailing spaces can be part of a grid, so only the line breaks are trimmed.
+    let input = input.trim_end_matches(['\n', '\r']);
+    if input.trim().is_empty() {
+        return None;
+    }
+
+    if input.trim().parse::<i64>().is_ok() {
+        return Some(Shape::Integer);
+    }
+
+    let lines: Vec<&str> = input.lines().collect();
+    let has_blank_lines = lines.iter().any(|line| line.trim().is_empty());
+    let counts: Vec<usize> = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| numbers(line).len())
+        .collect();
+    let same_count = counts.windows(2).all(|w| w[0] == w[1]);
+
+    // a line with several numbers is a record, even if all lines happen to have the same width.
+    if lines.len() > 1 && !has_blank_lines && counts[0] < 2 {
+        let width = lines[0].chars().count();
+        if width > 1
+            && lines
+                .iter()
+                .all(|line| line.chars().count() == width && !has_words(line))
+        {
+            return Some(Shape::Grid);
+        }
+    }
+
+    // blank lines between single numbers separate groups of them, so only several numbers make a record.
+    if same_count && counts[0] > 0 && (counts[0] > 1 || !has_blank_lines) {
+        return Some(Shape::Records);
+    }
+
+    if has_blank_lines {
+        return Some(Shape::Blocks);
+    }
+
+    None
+}
+
+/// Whether a line reads like text, i.e. one word follows another. Letters in a grid stand on their own.
+fn has_words(line: &str) -> bool {
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    tokens.windows(2).any(|w| {
+        w[0].ends_with(|c: char| c.is_alphabetic()) && w[1].starts_with(|c: char| c.is_alphabetic())
+    })
+}
+
+/// The integers in a line, like the `numbers` helper of the records template.
+fn numbers(line: &str) -> Vec<i64> {
+    line.split(|c: char| !c.is_ascii_digit() && c != '-')
+        .filter_map(|s| s.parse().ok())
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{detect, Shape};
+
+    #[test]
+    fn detects_integers() {
+        assert_eq!(detect("7347\n"), Some(Shape::Integer));
+        assert_eq!(detect("-12"), Some(Shape::Integer));
+    }
+
+    #[test]
+    fn detects_grids() {
+        let tracks = "/->-\\        \n|   |  /----\\\n| /-+--+-\\  |\n| | |  | v  |\n\\-+-/  \\-+--/\n  \\------/   \n";
+        assert_eq!(detect(tracks), Some(Shape::Grid));
+
+        let cave = "#######\n#.G...#\n#...EG#\n#######\n";
+        assert_eq!(detect(cave), Some(Shape::Grid));
+
+        assert_eq!(detect("30373\n25512\n65332\n"), Some(Shape::Grid));
+        assert_eq!(detect("MMMSXXMASM\nMSAMXMSMSA\n"), Some(Shape::Grid));
+    }
+
+    #[test]
+    fn detects_records() {
+        let samples = "Before: [3, 2, 1, 1]\n9 2 1 2\nAfter:  [3, 2, 2, 1]\n\nBefore: [0, 1, 2, 1]\n7 1 2 3\nAfter:  [0, 1, 2, 1]\n\n\n\n7 3 2 0\n7 2 1 1\n";
+        assert_eq!(detect(samples), Some(Shape::Records));
+
+        assert_eq!(detect("1, 1\n1, 6\n8, 3\n"), Some(Shape::Records));
+        assert_eq!(detect("+1\n-2\n+13\n"), Some(Shape::Records));
+        assert_eq!(
+            detect("position=< 9,  1> velocity=< 0,  2>\nposition=<-3, 11> velocity=< 1, -2>\n"),
+            Some(Shape::Records)
+        );
+    }
+
+    #[test]
+    fn detects_blocks() {
+        assert_eq!(
+            detect("1000\n2000\n\n4000\n\n5000\n6000\n"),
+            Some(Shape::Blocks)
+        );
+        assert_eq!(
+            detect("initial state: #..#.#\n\n...## => #\n..#.. => #\n"),
+            Some(Shape::Blocks)
+        );
+    }
+
+    #[test]
+    fn detects_nothing_else() {
+        assert_eq!(detect(""), None);
+        assert_eq!(detect("\n\n"), None);
+        assert_eq!(detect("dabAcCaCBAcCcaDA\n"), None);
+        assert_eq!(
+            detect("Step C must be finished before step A can begin.\nStep C must be finished before step F can begin.\n"),
+            None
+        );
+    }
+}
@@ -0,0 +1,400 @@
+/// Module that parses and renders the JSON of a private leaderboard.
+use std::{collections::HashMap, fmt::Write, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{all_days, Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// A member of a private leaderboard.
+#[derive(Clone, Debug)]
+pub struct Member {
+    pub id: String,
+    pub name: String,
+    pub local_score: u32,
+    pub stars: u32,
+    /// Unix timestamps of every star, keyed by day and part.
+    pub completion: HashMap<(Day, u8), i64>,
+}
+
+impl Member {
+    pub fn star_ts(&self, day: Day, part: u8) -> Option<i64> {
+        self.completion.get(&(day, part)).copied()
+    }
+
+    fn last_star_ts(&self) -> i64 {
+        self.completion.values().copied().max().unwrap_or(0)
+    }
+}
+
+/// A private leaderboard, as returned by `/{year}/leaderboard/private/view/{id}.json`.
+#[derive(Clone, Debug)]
+pub struct Leaderboard {
+    pub event: u16,
+    pub members: Vec<Member>,
+}
+
+impl Leaderboard {
+    /// Returns members by local score, ties are broken by who got their last star first.
+    pub fn ranked(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.iter().collect();
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(a.last_star_ts().cmp(&b.last_star_ts()))
+                .then(a.name.cmp(&b.name))
+        });
+        members
+    }
+
+    /// Returns the last day for which any member has a star.
+    pub fn last_day(&self) -> Option<Day> {
+        self.members
+            .iter()
+            .flat_map(|m| m.completion.keys().map(|(day, _)| *day))
+            .max()
+    }
+
+    /// Returns the local score every member got for a single day.
+    /// The first member to get a star receives one point per member, the second one point less, and so on.
+    pub fn day_scores(&self, day: Day) -> HashMap<&str, u32> {
+        let mut scores: HashMap<&str, u32> = HashMap::new();
+        let member_count = u32::try_from(self.members.len()).unwrap_or(u32::MAX);
+
+        for part in [1, 2] {
+            let mut solvers: Vec<(&str, i64)> = self
+                .members
+                .iter()
+                .filter_map(|m| Some((m.id.as_str(), m.star_ts(day, part)?)))
+                .collect();
+            solvers.sort_by_key(|(_, ts)| *ts);
+
+            for ((id, _), rank) in solvers.into_iter().zip(0..) {
+                *scores.entry(id).or_default() += member_count.saturating_sub(rank);
+            }
+        }
+
+        scores
+    }
+
+    /// Seconds between the unlock of a puzzle and a member's star.
+    pub fn time_to_star(&self, member: &Member, day: Day, part: u8) -> Option<i64> {
+        member
+            .star_ts(day, part)
+            .map(|ts| ts - day.unlock_timestamp(self.event))
+    }
+}
+
+/// Formats a duration in seconds as `HH:MM:SS`.
+pub fn format_duration(secs: i64) -> String {
+    let secs = secs.max(0);
+    format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Renders the ranked table with stars per day and local score.
+pub fn render_overview(leaderboard: &Leaderboard) -> String {
+    let days: Vec<Day> = all_days()
+        .take_while(|day| leaderboard.last_day().is_some_and(|last| *day <= last))
+        .collect();
+
+    let mut out = String::new();
+    let indent = " ".repeat(17);
+
+    let tens: String = days.iter().map(|d| d.to_string().remove(0)).collect();
+    let ones: String = days.iter().map(|d| d.to_string().remove(1)).collect();
+    let _ = writeln!(out, "{indent}{}", tens.replace('0', " "));
+    let _ = writeln!(out, "{indent}{ones}");
+
+    for (member, rank) in leaderboard.ranked().into_iter().zip(1..) {
+        let grid: String = days
+            .iter()
+            .map(
+                |day| match (member.star_ts(*day, 1), member.star_ts(*day, 2)) {
+                    (Some(_), Some(_)) => '★',
+                    (Some(_), None) => '☆',
+                    _ => '·',
+                },
+            )
+            .collect();
+
+        let _ = writeln!(
+            out,
+            "{rank:>3}) {ANSI_BOLD}{:>5}{ANSI_RESET} {:>3}★  {grid}  {}",
+            member.local_score, member.stars, member.name
+        );
+    }
+
+    out
+}
+
+/// Renders the time every member took to each star of a day, with the local score for that day.
+pub fn render_day(leaderboard: &Leaderboard, day: Day) -> String {
+    let scores = leaderboard.day_scores(day);
+
+    let mut members: Vec<&Member> = leaderboard
+        .members
+        .iter()
+        .filter(|m| m.star_ts(day, 1).is_some())
+        .collect();
+    members.sort_by_key(|m| {
+        (
+            std::cmp::Reverse(scores.get(m.id.as_str()).copied().unwrap_or(0)),
+            m.star_ts(day, 2).unwrap_or(i64::MAX),
+        )
+    });
+
+    let mut out = String::new();
+    let _ = writeln!(out, "{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    let _ = writeln!(
+        out,
+        "      {:>8}  {:>8}  {:>8}  {:>6}",
+        "Part 1", "Part 2", "Delta", "Score"
+    );
+
+    for (member, rank) in members.into_iter().zip(1..) {
+        let part_1 = leaderboard.time_to_star(member, day, 1);
+        let part_2 = leaderboard.time_to_star(member, day, 2);
+        let delta = part_1.zip(part_2).map(|(a, b)| b - a);
+        let format = |t: Option<i64>| t.map_or_else(|| "-".into(), format_duration);
+
+        let _ = writeln!(
+            out,
+            "{rank:>3})  {:>8}  {:>8}  {ANSI_ITALIC}{:>8}{ANSI_RESET}  {ANSI_BOLD}{:>6}{ANSI_RESET}  {}",
+            format(part_1),
+            format(part_2),
+            format(delta),
+            scores.get(member.id.as_str()).copied().unwrap_or(0),
+            member.name
+        );
+    }
+
+    out
+}
+
+/// Renders the members that are missing one or both stars of a day.
+pub fn render_missing(leaderboard: &Leaderboard, day: Day) -> String {
+    let mut out = String::new();
+
+    for (part, label) in [(1, "Missing part 1"), (2, "Missing part 2 only")] {
+        let mut names: Vec<&str> = leaderboard
+            .members
+            .iter()
+            .filter(|m| {
+                m.star_ts(day, part).is_none() && (part == 1 || m.star_ts(day, 1).is_some())
+            })
+            .map(|m| m.name.as_str())
+            .collect();
+        names.sort_unstable();
+
+        let names = if names.is_empty() {
+            "-".to_string()
+        } else {
+            names.join(", ")
+        };
+        let _ = writeln!(out, "{ANSI_BOLD}{label}:{ANSI_RESET} {names}");
+    }
+
+    out
+}
+
+/// Renders the time to each star for every member and day.
+pub fn render_times(leaderboard: &Leaderboard) -> String {
+    let mut out = String::new();
+
+    for member in leaderboard.ranked() {
+        let _ = writeln!(out, "{ANSI_BOLD}{}{ANSI_RESET}", member.name);
+
+        for day in all_days() {
+            let Some(part_1) = leaderboard.time_to_star(member, day, 1) else {
+                continue;
+            };
+            let part_2 = leaderboard
+                .time_to_star(member, day, 2)
+                .map_or_else(|| "-".into(), format_duration);
+            let _ = writeln!(
+                out,
+                "  Day {day}: {:>8}  {:>8}",
+                format_duration(part_1),
+                part_2
+            );
+        }
+    }
+
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl TryFrom<String> for Leaderboard {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value)
+            .or(Err("not valid JSON, the session token might be invalid."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let event = json
+            .get("event")
+            .and_then(|v| v.get::<String>())
+            .and_then(|v| v.parse().ok())
+            .ok_or("expected `json.event` to be a year.")?;
+
+        let members = json
+            .get("members")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("expected `json.members` to be an object.")?
+            .values()
+            .map(Member::try_from)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Leaderboard { event, members })
+    }
+}
+
+impl TryFrom<&JsonValue> for Member {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected member to be a JSON object.")?;
+
+        let number = |key: &str| -> Result<f64, String> {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected member.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let (id, local_score, stars) = (
+            number("id")? as u64,
+            number("local_score")? as u32,
+            number("stars")? as u32,
+        );
+
+        let name = json
+            .get("name")
+            .and_then(|v| v.get::<String>())
+            .cloned()
+            .unwrap_or_else(|| format!("(anonymous user #{id})"));
+
+        let mut completion = HashMap::new();
+        let days = json
+            .get("completion_day_level")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("Expected member.completion_day_level to be an object.")?;
+
+        for (day, parts) in days {
+            let day = Day::from_str(day).map_err(|e| e.to_string())?;
+            let parts = parts
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("Expected completion day to be an object.")?;
+
+            for (part, star) in parts {
+                let part: u8 = part.parse().or(Err("Expected part to be 1 or 2."))?;
+                #[allow(clippy::cast_possible_truncation)]
+                let ts = star
+                    .get::<HashMap<String, JsonValue>>()
+                    .and_then(|s| s.get("get_star_ts"))
+                    .and_then(|v| v.get::<f64>())
+                    .ok_or("Expected star to have a `get_star_ts` number.")?
+                    .round() as i64;
+                completion.insert((day, part), ts);
+            }
+        }
+
+        Ok(Member {
+            id: id.to_string(),
+            name,
+            local_score,
+            stars,
+            completion,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_duration, render_missing, Leaderboard};
+    use crate::day;
+
+    // day 1 of 2018 unlocked at 1543640400.
+    const FIXTURE: &str = r#"{
+        "event": "2018",
+        "owner_id": 1,
+        "members": {
+            "1": {
+                "id": 1, "name": "alice", "stars": 2, "local_score": 6, "global_score": 0, "last_star_ts": 1543641000,
+                "completion_day_level": {
+                    "1": {
+                        "1": { "get_star_ts": 1543640700, "star_index": 0 },
+                        "2": { "get_star_ts": 1543641000, "star_index": 1 }
+                    }
+                }
+            },
+            "2": {
+                "id": 2, "name": "bob", "stars": 1, "local_score": 2, "global_score": 0, "last_star_ts": 1543644000,
+                "completion_day_level": {
+                    "1": { "1": { "get_star_ts": 1543644000, "star_index": 2 } }
+                }
+            },
+            "3": {
+                "id": 3, "name": null, "stars": 0, "local_score": 0, "global_score": 0, "last_star_ts": 0,
+                "completion_day_level": {}
+            }
+        }
+    }"#;
+
+    fn fixture() -> Leaderboard {
+        Leaderboard::try_from(FIXTURE.to_string()).unwrap()
+    }
+
+    #[test]
+    fn parses_members() {
+        let leaderboard = fixture();
+        assert_eq!(leaderboard.event, 2018);
+        assert_eq!(leaderboard.members.len(), 3);
+        assert_eq!(leaderboard.last_day(), Some(day!(1)));
+    }
+
+    #[test]
+    fn ranks_by_local_score() {
+        let leaderboard = fixture();
+        let names: Vec<&str> = leaderboard
+            .ranked()
+            .iter()
+            .map(|m| m.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["alice", "bob", "(anonymous user #3)"]);
+    }
+
+    #[test]
+    fn computes_day_scores() {
+        let leaderboard = fixture();
+        let scores = leaderboard.day_scores(day!(1));
+        assert_eq!(scores.get("1"), Some(&6));
+        assert_eq!(scores.get("2"), Some(&2));
+        assert_eq!(scores.get("3"), None);
+    }
+
+    #[test]
+    fn computes_time_to_star() {
+        let leaderboard = fixture();
+        let alice = leaderboard.ranked()[0];
+        assert_eq!(leaderboard.time_to_star(alice, day!(1), 1), Some(300));
+        assert_eq!(leaderboard.time_to_star(alice, day!(1), 2), Some(600));
+        assert_eq!(format_duration(3600 * 26 + 61), "26:01:01");
+    }
+
+    #[test]
+    fn lists_missing_members() {
+        let s = render_missing(&fixture(), day!(1));
+        assert!(s.contains("(anonymous user #3)"));
+        assert!(s.lines().nth(1).unwrap().contains("bob"));
+    }
+}
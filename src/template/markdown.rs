@@ -0,0 +1,343 @@
+/// Module that renders the markdown of puzzle descriptions for the terminal.
+/// Supports the subset of markdown that aoc-cli writes: headings, paragraphs, lists, code blocks and inline emphasis.
+use std::{env, process::Command};
+
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+const DEFAULT_WIDTH: usize = 80;
+
+/// Returns the width of the terminal, falling back to 80 columns.
+pub fn terminal_width() -> usize {
+    let from_env = env::var("COLUMNS").ok().and_then(|c| c.trim().parse().ok());
+
+    let from_tput = || {
+        let output = Command::new("tput").arg("cols").output().ok()?;
+        String::from_utf8_lossy(&output.stdout).trim().parse().ok()
+    };
+
+    from_env
+        .or_else(from_tput)
+        .filter(|w: &usize| *w > 0)
+        .unwrap_or(DEFAULT_WIDTH)
+}
+
+/// Renders markdown to text with ANSI styles, wrapped to `width` columns.
+pub fn render(markdown: &str, width: usize) -> String {
+    let mut out: Vec<String> = vec![];
+    let mut paragraph: Vec<&str> = vec![];
+    let mut code: Option<Vec<&str>> = None;
+
+    let lines: Vec<&str> = markdown.lines().collect();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        i += 1;
+
+        if let Some(block) = code.as_mut() {
+            if line.trim_start().starts_with("```") {
+                out.extend(render_code_block(block));
+                out.push(String::new());
+                code = None;
+            } else {
+                block.push(line);
+            }
+            continue;
+        }
+
+        if line.trim_start().starts_with("```") {
+            flush_paragraph(&mut paragraph, &mut out, width);
+            code = Some(vec![]);
+            continue;
+        }
+
+        if line.trim().is_empty() {
+            flush_paragraph(&mut paragraph, &mut out, width);
+            continue;
+        }
+
+        // setext headings, as written by aoc-cli: `\--- Day 1: Title ---` underlined by dashes.
+        if lines.get(i).is_some_and(|next| is_underline(next)) {
+            flush_paragraph(&mut paragraph, &mut out, width);
+            out.push(format!("{ANSI_BOLD}{}{ANSI_RESET}", unescape(line.trim())));
+            out.push(String::new());
+            i += 1;
+            continue;
+        }
+
+        if let Some(heading) = line.trim_start().strip_prefix('#') {
+            flush_paragraph(&mut paragraph, &mut out, width);
+            let heading = heading.trim_start_matches('#').trim();
+            out.push(format!("{ANSI_BOLD}{}{ANSI_RESET}", render_inline(heading)));
+            out.push(String::new());
+            continue;
+        }
+
+        if let Some(item) = list_item(line) {
+            flush_paragraph(&mut paragraph, &mut out, width);
+            out.extend(wrap(&render_inline(item), width, "• ", "  "));
+            if lines.get(i).is_none_or(|next| list_item(next).is_none()) {
+                out.push(String::new());
+            }
+            continue;
+        }
+
+        paragraph.push(line.trim());
+    }
+
+    flush_paragraph(&mut paragraph, &mut out, width);
+
+    if let Some(block) = code {
+        out.extend(render_code_block(&block));
+    }
+
+    while out.last().is_some_and(String::is_empty) {
+        out.pop();
+    }
+
+    out.join("\n")
+}
+
+fn flush_paragraph(paragraph: &mut Vec<&str>, out: &mut Vec<String>, width: usize) {
+    if paragraph.is_empty() {
+        return;
+    }
+    out.extend(wrap(&render_inline(&paragraph.join(" ")), width, "", ""));
+    out.push(String::new());
+    paragraph.clear();
+}
+
+fn is_underline(line: &str) -> bool {
+    let line = line.trim();
+    line.len() >= 3 && line.chars().all(|c| c == '-' || c == '=')
+}
+
+fn list_item(line: &str) -> Option<&str> {
+    let line = line.trim_start();
+    line.strip_prefix("* ")
+        .or_else(|| line.strip_prefix("- "))
+        .or_else(|| line.strip_prefix("+ "))
+}
+
+fn unescape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if let Some(next) = chars.next() {
+                out.push(next);
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Replaces inline markdown with ANSI styles: `**bold**`, `*italic*`, `` `code` `` and `[links](url)`.
+fn render_inline(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut bold = false;
+    let mut italic = false;
+    let mut chars = s.chars().peekable();
+
+    let restore = |bold: bool, italic: bool| {
+        let mut codes = ANSI_RESET.to_string();
+        if bold {
+            codes.push_str(ANSI_BOLD);
+        }
+        if italic {
+            codes.push_str(ANSI_ITALIC);
+        }
+        codes
+    };
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(next) = chars.next() {
+                    out.push(next);
+                }
+            }
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                bold = !bold;
+                out.push_str(&restore(bold, italic));
+            }
+            // a lone asterisk, e.g. in `1 * 2`, is not an emphasis marker.
+            '*' if !italic && chars.peek().is_none_or(|c| c.is_whitespace()) => out.push(c),
+            '*' => {
+                italic = !italic;
+                out.push_str(&restore(bold, italic));
+            }
+            '`' => {
+                // inline code is printed verbatim, except for emphasis which aoc-cli writes as `*code*`.
+                let code: String = chars.by_ref().take_while(|c| *c != '`').collect();
+                match code.strip_prefix('*').and_then(|c| c.strip_suffix('*')) {
+                    Some(emphasized) if !emphasized.is_empty() => {
+                        out.push_str(ANSI_BOLD);
+                        out.push_str(emphasized);
+                        out.push_str(&restore(bold, italic));
+                    }
+                    _ => out.push_str(&code),
+                }
+            }
+            '[' => {
+                let rest: String = chars.clone().collect();
+                match rest.find("](").and_then(|end| {
+                    let close = rest[end..].find(')')? + end;
+                    Some((end, close))
+                }) {
+                    Some((end, close)) => {
+                        out.push_str(&render_inline(&rest[..end]));
+                        for _ in 0..rest[..=close].chars().count() {
+                            chars.next();
+                        }
+                    }
+                    None => out.push(c),
+                }
+            }
+            c => out.push(c),
+        }
+    }
+
+    if bold || italic {
+        out.push_str(ANSI_RESET);
+    }
+
+    out
+}
+
+/// Returns the number of visible characters, ignoring ANSI escape sequences.
+fn visible_width(s: &str) -> usize {
+    let mut width = 0;
+    let mut in_escape = false;
+    for c in s.chars() {
+        match c {
+            '\x1b' => in_escape = true,
+            'm' if in_escape => in_escape = false,
+            _ if in_escape => {}
+            _ => width += 1,
+        }
+    }
+    width
+}
+
+/// Removes all ANSI escape sequences, e.g. from the captured output of a command.
+pub fn strip_ansi(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // skip until the final byte of the escape sequence.
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Wraps styled text to `width` columns. The first line is prefixed by `first`, all others by `rest`.
+fn wrap(text: &str, width: usize, first: &str, rest: &str) -> Vec<String> {
+    let mut lines = vec![];
+    let mut line = first.to_string();
+    let mut line_width = visible_width(first);
+    let mut is_empty = true;
+
+    for word in text.split_whitespace() {
+        let word_width = visible_width(word);
+        if !is_empty && line_width + 1 + word_width > width {
+            lines.push(std::mem::replace(&mut line, rest.to_string()));
+            line_width = visible_width(rest);
+            is_empty = true;
+        }
+        if !is_empty {
+            line.push(' ');
+            line_width += 1;
+        }
+        line.push_str(word);
+        line_width += word_width;
+        is_empty = false;
+    }
+
+    lines.push(line);
+    lines
+}
+
+fn render_code_block(lines: &[&str]) -> Vec<String> {
+    let inner = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+
+    let mut out = vec![format!("┌{}┐", "─".repeat(inner + 2))];
+    for line in lines {
+        let padding = inner - line.chars().count();
+        out.push(format!("│ {line}{} │", " ".repeat(padding)));
+    }
+    out.push(format!("└{}┘", "─".repeat(inner + 2)));
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{render, visible_width};
+    use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+    #[test]
+    fn renders_setext_headings() {
+        let s = render("\\--- Day 1: Chronal Calibration ---\n----------\n", 80);
+        assert_eq!(
+            s,
+            format!("{ANSI_BOLD}--- Day 1: Chronal Calibration ---{ANSI_RESET}")
+        );
+    }
+
+    #[test]
+    fn wraps_paragraphs() {
+        let s = render("one two three\nfour five", 9);
+        assert_eq!(s, "one two\nthree\nfour five");
+    }
+
+    #[test]
+    fn styles_emphasis() {
+        let s = render("a *b* `*c*` **d**", 80);
+        assert_eq!(
+            s,
+            format!(
+                "a {ANSI_RESET}{ANSI_ITALIC}b{ANSI_RESET} {ANSI_BOLD}c{ANSI_RESET} {ANSI_RESET}{ANSI_BOLD}d{ANSI_RESET}"
+            )
+        );
+    }
+
+    #[test]
+    fn keeps_lone_asterisks() {
+        assert_eq!(render("1721 * 299 = 514579", 80), "1721 * 299 = 514579");
+    }
+
+    #[test]
+    fn ignores_styles_when_wrapping() {
+        let s = render("*aaa* *bbb*", 7);
+        assert_eq!(s.lines().count(), 1);
+        assert_eq!(visible_width(&s), 7);
+    }
+
+    #[test]
+    fn boxes_code_blocks() {
+        let s = render("```\n#.#\n#\n```\n", 80);
+        assert_eq!(s, "┌─────┐\n│ #.# │\n│ #   │\n└─────┘");
+    }
+
+    #[test]
+    fn renders_lists_and_links() {
+        let s = render(
+            "* see [the page](https://adventofcode.com) now\n* two\n",
+            80,
+        );
+        assert_eq!(s, "• see the page now\n• two");
+    }
+}
@@ -1,14 +1,40 @@
-use std::{env, fs};
+use std::{env, fs, path::Path, process};
 
 pub mod aoc_cli;
 pub mod commands;
 pub mod runner;
 
 pub use day::*;
+pub use input::*;
+pub use selection::*;
 
+mod accounts;
+mod answers;
+mod bundle;
+mod cache;
+pub mod config;
+mod dashboard;
 mod day;
+mod doctor;
+pub mod error;
+mod event_stats;
+mod example_manifest;
+mod git;
+mod hooks;
+mod input_shape;
+pub mod http;
+mod input;
+pub mod leaderboard;
+pub mod markdown;
+pub mod module_template;
+pub mod puzzle;
 mod readme_benchmarks;
 mod run_multi;
+mod search;
+mod selection;
+mod stats;
+mod status;
+mod test_report;
 mod timings;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
@@ -16,12 +42,41 @@ pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
 /// Helper function that reads a text file to a string.
+/// The folder (e.g. `inputs`) is resolved through the paths of the project configuration.
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    let filepath = cwd
+        .join(config::get().folder(folder))
+        .join(format!("{day}.txt"));
+    read_file_at(&filepath)
+}
+
+/// Helper function that reads the input a solution runs against.
+/// Defaults to the puzzle input, `--input <path>`, `--example [N]` and `--stdin` select another source.
+#[must_use]
+pub fn read_input(day: Day) -> String {
+    let args: Vec<String> = env::args().collect();
+
+    let source = match InputSource::from_args(&args) {
+        Ok(source) => source,
+        Err(e) => {
+            eprintln!("{e}.");
+            process::exit(1);
+        }
+    };
+
+    if source != InputSource::Puzzle {
+        println!("{ANSI_ITALIC}Input: {}{ANSI_RESET}", source.describe(day));
+    }
+
+    match source.read(day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Could not read input from {}: {e}", source.describe(day));
+            process::exit(1);
+        }
+    }
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
@@ -29,11 +84,27 @@ pub fn read_file(folder: &str, day: Day) -> String {
 pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
-        .join(folder)
+        .join(config::get().folder(folder))
         .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    read_file_at(&filepath)
+}
+
+/// Reads a file for a test. An empty file is noted in the output that the test harness captures,
+/// so that `cargo test-day` can skip a test that fails because it read an empty example.
+fn read_file_at(path: &Path) -> String {
+    let f = fs::read_to_string(path).expect(test_report::MISSING_FILE_MESSAGE);
+    if f.trim().is_empty() {
+        eprintln!("{}: {}", test_report::EMPTY_FILE_MESSAGE, path.display());
+    }
+    f
+}
+
+/// Helper function that returns the expected example answer of a part, as extracted by `cargo examples`.
+#[must_use]
+pub fn read_example_answer(day: Day, part: u8) -> Option<String> {
+    example_manifest::ExampleManifest::read_from_file()
+        .get(day)
+        .and_then(|entry| entry.answer(part).map(ToString::to_string))
 }
 
 /// Creates the constant `DAY` and sets up the input and runner for each part.
@@ -61,7 +132,7 @@ macro_rules! solution {
 
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
+            let input = $crate::template::read_input(DAY);
             $( run_part($func, &input, DAY, $part); )*
         }
     };
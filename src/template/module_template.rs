@@ -0,0 +1,319 @@
+/// Module that renders the templates that `cargo scaffold` creates solutions from.
+/// Besides the embedded ones, templates are read from the templates folder, e.g. `templates/grid.txt`.
+/// A template refers to variables like `%DAY%` and can declare its own at the top, with an optional default:
+///
+/// ```text
+/// // @var ANSWER_TYPE = u64
+/// // @var PARSER
+/// ```
+use std::{collections::HashMap, fs, path::Path};
+
+use crate::template::{config, Day};
+
+macro_rules! embed {
+    ($name:literal) => {
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/",
+            $name,
+            ".txt"
+        ))
+    };
+}
+
+const DEFAULT_TEMPLATE: &str =
+    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+
+/// The name of the template that is used if no other is chosen.
+pub const DEFAULT_NAME: &str = "default";
+
+/// Templates that are embedded, besides the default one with a parser for each input shape that is detected.
+/// A file of the same name in the templates folder replaces them.
+const EMBEDDED: [(&str, &str); 5] = [
+    (DEFAULT_NAME, DEFAULT_TEMPLATE),
+    ("blocks", embed!("blocks")),
+    ("grid", embed!("grid")),
+    ("integer", embed!("integer")),
+    ("records", embed!("records")),
+];
+
+const DECLARATION_PREFIX: &str = "// @var ";
+
+/// Variables that are always available, and their value if it is not known.
+const BUILTIN_VARIABLES: [(&str, &str); 5] = [
+    ("DAY", ""),
+    ("DAY_NUMBER", ""),
+    ("YEAR", ""),
+    ("TITLE", ""),
+    ("ANSWER_TYPE", "u32"),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModuleTemplate {
+    pub name: String,
+    /// The template without its declarations.
+    body: String,
+    /// Declared variables and their default, if any.
+    declared: Vec<(String, Option<String>)>,
+}
+
+impl ModuleTemplate {
+    /// Splits the declarations at the top of a template from its body.
+    pub fn parse(name: &str, source: &str) -> Result<Self, String> {
+        let mut declared = vec![];
+        let mut lines = source.lines().peekable();
+
+        while let Some(declaration) = lines
+            .peek()
+            .and_then(|line| line.trim().strip_prefix(DECLARATION_PREFIX))
+        {
+            let (variable, default) = match declaration.split_once('=') {
+                Some((variable, default)) => (variable.trim(), Some(default.trim().to_string())),
+                None => (declaration.trim(), None),
+            };
+            if !is_variable_name(variable) {
+                return Err(format!(
+                    "Template \"{name}\" declares the invalid variable `{variable}`, use uppercase letters and underscores."
+                ));
+            }
+            declared.push((variable.to_string(), default));
+            lines.next();
+        }
+
+        let body: Vec<&str> = lines.collect();
+        let mut body = body.join("\n").trim_start_matches('\n').to_string();
+        if source.ends_with('\n') {
+            body.push('\n');
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            body,
+            declared,
+        })
+    }
+
+    /// Loads a template from the templates folder, falling back to the embedded templates.
+    pub fn load(name: &str) -> Result<Self, String> {
+        let path = Path::new(&config::get().paths.templates).join(format!("{name}.txt"));
+
+        match fs::read_to_string(&path) {
+            Ok(source) => Self::parse(name, &source),
+            Err(_) => match EMBEDDED.iter().find(|(embedded, _)| *embedded == name) {
+                Some((_, source)) => Self::parse(name, source),
+                None => {
+                    let available = available().join(", ");
+                    Err(format!(
+                        "Template \"{name}\" not found at \"{}\". Available templates: {available}.",
+                        path.display()
+                    ))
+                }
+            },
+        }
+    }
+
+    /// Replaces the variables of the template. `values` holds what is known about the day
+    /// and the values passed with `--var`, they take precedence over declared defaults.
+    pub fn render(&self, values: &HashMap<String, String>) -> Result<String, String> {
+        let mut variables: HashMap<&str, &str> = BUILTIN_VARIABLES.into_iter().collect();
+
+        for (variable, default) in &self.declared {
+            match (values.get(variable), default) {
+                (Some(_), _) => {}
+                (None, Some(default)) => {
+                    variables.insert(variable, default);
+                }
+                (None, None) => {
+                    return Err(format!(
+                        "Template \"{}\" needs a value for `{variable}`, pass it with `--var {variable}=<value>`.",
+                        self.name
+                    ));
+                }
+            }
+        }
+
+        for (variable, value) in values {
+            variables.insert(variable, value);
+        }
+
+        let mut out = String::new();
+        let mut rest = self.body.as_str();
+
+        // placeholders look like `%NAME%`, a `%` that does not start one is kept as is.
+        while let Some(start) = rest.find('%') {
+            out.push_str(&rest[..start]);
+            let after = &rest[start + 1..];
+
+            match after.find('%').map(|end| &after[..end]) {
+                Some(variable) if is_variable_name(variable) => {
+                    let value = variables.get(variable).ok_or_else(|| {
+                        format!(
+                            "Template \"{}\" uses `%{variable}%`, which is not declared.",
+                            self.name
+                        )
+                    })?;
+                    out.push_str(value);
+                    rest = &after[variable.len() + 1..];
+                }
+                _ => {
+                    out.push('%');
+                    rest = after;
+                }
+            }
+        }
+
+        out.push_str(rest);
+        Ok(out)
+    }
+}
+
+/// The values of the built-in variables that are known for a day.
+pub fn day_values(day: Day, year: Option<u16>, title: Option<String>) -> HashMap<String, String> {
+    let mut values = HashMap::from([
+        ("DAY".to_string(), day.to_string()),
+        ("DAY_NUMBER".to_string(), day.into_inner().to_string()),
+    ]);
+    if let Some(year) = year {
+        values.insert("YEAR".into(), year.to_string());
+    }
+    values.insert(
+        "TITLE".into(),
+        title.unwrap_or_else(|| format!("Day {}", day.into_inner())),
+    );
+    values
+}
+
+/// Parses a `--var NAME=value` argument.
+pub fn parse_variable(arg: &str) -> Result<(String, String), String> {
+    match arg.split_once('=') {
+        Some((variable, value)) if is_variable_name(variable.trim()) => {
+            Ok((variable.trim().to_string(), value.to_string()))
+        }
+        _ => Err(format!(
+            "expected `--var NAME=value` with an uppercase NAME, got `{arg}`"
+        )),
+    }
+}
+
+/// Names of the templates in the templates folder and the embedded templates.
+pub fn available() -> Vec<String> {
+    let mut names: Vec<String> = EMBEDDED.iter().map(|(name, _)| name.to_string()).collect();
+
+    if let Ok(entries) = fs::read_dir(&config::get().paths.templates) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().is_some_and(|e| e == "txt") {
+                if let Some(name) = path.file_stem().map(|s| s.to_string_lossy().to_string()) {
+                    if !names.contains(&name) {
+                        names.push(name);
+                    }
+                }
+            }
+        }
+    }
+
+    names.sort();
+    names
+}
+
+fn is_variable_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{day_values, parse_variable, ModuleTemplate, DEFAULT_TEMPLATE, EMBEDDED};
+    use crate::day;
+
+    const GRID: &str = "// @var ANSWER_TYPE = usize
+// @var NEIGHBOURS = 4
+
+// Day %DAY%, %YEAR%: %TITLE%
+advent_of_code::solution!(%DAY_NUMBER%);
+
+pub fn part_one(input: &str) -> Option<%ANSWER_TYPE%> {
+    let _ = %NEIGHBOURS% % 2;
+    None
+}
+";
+
+    #[test]
+    fn renders_declared_variables() {
+        let template = ModuleTemplate::parse("grid", GRID).unwrap();
+        let values = day_values(day!(17), Some(2018), None);
+
+        assert_eq!(
+            template.render(&values).unwrap(),
+            "// Day 17, 2018: Day 17
+advent_of_code::solution!(17);
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let _ = 4 % 2;
+    None
+}
+"
+        );
+    }
+
+    #[test]
+    fn prefers_passed_values() {
+        let template = ModuleTemplate::parse("grid", GRID).unwrap();
+        let mut values = day_values(day!(3), None, Some("No Matter How You Slice It".into()));
+        values.insert("ANSWER_TYPE".into(), "u64".into());
+
+        let rendered = template.render(&values).unwrap();
+        assert!(rendered.starts_with("// Day 03, : No Matter How You Slice It\n"));
+        assert!(rendered.contains("Option<u64>"));
+    }
+
+    #[test]
+    fn requires_variables() {
+        let template = ModuleTemplate::parse("custom", "// @var PARSER\n%PARSER%\n").unwrap();
+        assert!(template
+            .render(&day_values(day!(1), None, None))
+            .is_err_and(|e| e.contains("--var PARSER=")));
+
+        let template = ModuleTemplate::parse("custom", "%UNKNOWN%\n").unwrap();
+        assert!(template
+            .render(&day_values(day!(1), None, None))
+            .is_err_and(|e| e.contains("not declared")));
+
+        assert!(ModuleTemplate::parse("custom", "// @var lower = 1\n").is_err());
+    }
+
+    #[test]
+    fn renders_the_default_template() {
+        let template = ModuleTemplate::parse("default", DEFAULT_TEMPLATE).unwrap();
+        let rendered = template.render(&day_values(day!(9), None, None)).unwrap();
+        assert!(rendered.starts_with("advent_of_code::solution!(9);\n"));
+        assert!(rendered.contains("pub fn part_one(input: &str) -> Option<u32> {"));
+    }
+
+    #[test]
+    fn renders_the_embedded_templates() {
+        for (name, source) in EMBEDDED {
+            let template = ModuleTemplate::parse(name, source).unwrap();
+            let rendered = template.render(&day_values(day!(9), None, None)).unwrap();
+            assert!(
+                rendered.starts_with("advent_of_code::solution!(9);\n"),
+                "{name}"
+            );
+            assert!(!rendered.contains('%'), "{name}");
+        }
+    }
+
+    #[test]
+    fn parses_variables() {
+        assert_eq!(
+            parse_variable("ANSWER_TYPE=u64"),
+            Ok(("ANSWER_TYPE".into(), "u64".into()))
+        );
+        assert!(parse_variable("answer=u64").is_err());
+        assert!(parse_variable("ANSWER_TYPE").is_err());
+    }
+}
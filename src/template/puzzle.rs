@@ -0,0 +1,467 @@
+/// Module that extracts structured information from downloaded puzzle descriptions.
+/// Understands both the markdown written by aoc-cli and raw `<pre><code>` html blocks.
+use std::{fs, io};
+
+use crate::template::{aoc_cli, Day};
+
+const PART_TWO_MARKER: &str = "--- Part Two ---";
+const ANSWER_MARKER: &str = "Your puzzle answer was";
+
+/// Delimiters that surround emphasized inline code, i.e. the values that
+/// the puzzle text highlights as results of the example.
+const EMPHASIS_DELIMITERS: [(&str, &str); 4] = [
+    ("`*", "*`"),
+    ("*`", "`*"),
+    ("<code><em>", "</em></code>"),
+    ("<em><code>", "</code></em>"),
+];
+
+/// A code block found in the puzzle description.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodeBlock {
+    pub part: u8,
+    pub text: String,
+}
+
+/// The candidate values for the example answer of one part.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AnswerCandidates {
+    /// All emphasized values of the part, in order of appearance.
+    pub values: Vec<String>,
+    /// The value that most likely is the example answer, if there is a clear one.
+    pub best: Option<String>,
+}
+
+impl AnswerCandidates {
+    /// Whether the candidates are ambiguous and should be confirmed by a human.
+    pub fn is_ambiguous(&self) -> bool {
+        self.best.is_none() && !self.values.is_empty()
+    }
+
+    /// Reads the choice of a human: `#2` picks the second candidate, an empty line skips,
+    /// and anything else is taken as the answer as typed, even a number.
+    pub fn choose(&self, input: &str) -> Result<Option<String>, String> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Ok(None);
+        }
+
+        let Some(index) = input.strip_prefix('#') else {
+            return Ok(Some(input.to_string()));
+        };
+
+        index
+            .parse::<usize>()
+            .ok()
+            .and_then(|i| self.values.get(i.checked_sub(1)?))
+            .map(|value| Some(value.clone()))
+            .ok_or_else(|| format!("Pick a candidate from #1 to #{}.", self.values.len()))
+    }
+}
+
+/// A puzzle description, split into the text of its two parts.
+pub struct Puzzle {
+    parts: Vec<String>,
+}
+
+impl Puzzle {
+    /// Reads the puzzle description for a day from disk.
+    pub fn read(day: Day) -> Result<Self, io::Error> {
+        fs::read_to_string(aoc_cli::get_puzzle_path(day)).map(|s| Self::parse(&s))
+    }
+
+    pub fn parse(s: &str) -> Self {
+        let parts = match s.find(PART_TWO_MARKER) {
+            Some(pos) => vec![s[..pos].to_string(), s[pos..].to_string()],
+            None => vec![s.to_string()],
+        };
+        Self { parts }
+    }
+
+    /// Returns the text of a part (1 or 2), if the description contains it.
+    pub fn part(&self, part: u8) -> Option<&str> {
+        self.parts
+            .get(usize::from(part).checked_sub(1)?)
+            .map(String::as_str)
+    }
+
+    /// Returns all code blocks, in order of appearance.
+    pub fn code_blocks(&self) -> Vec<CodeBlock> {
+        self.parts
+            .iter()
+            .zip(1..)
+            .flat_map(|(text, part)| {
+                code_blocks(text)
+                    .into_iter()
+                    .map(move |text| CodeBlock { part, text })
+            })
+            .collect()
+    }
+
+    /// Returns the candidates for the example answer of a part.
+    /// The best guess is the emphasized value of the last paragraph that contains one.
+    pub fn answer_candidates(&self, part: u8) -> AnswerCandidates {
+        let Some(text) = self.part(part) else {
+            return AnswerCandidates::default();
+        };
+
+        let paragraphs: Vec<Vec<String>> = prose_paragraphs(text)
+            .iter()
+            .map(|p| emphasized_values(p))
+            .filter(|values| !values.is_empty())
+            .collect();
+
+        let best = paragraphs.last().and_then(|values| {
+            let first = values.first()?;
+            values.iter().all(|v| v == first).then(|| first.clone())
+        });
+
+        let mut values: Vec<String> = vec![];
+        for value in paragraphs.into_iter().flatten() {
+            if !values.contains(&value) {
+                values.push(value);
+            }
+        }
+
+        AnswerCandidates { values, best }
+    }
+
+    /// Returns the answer that the site confirmed for a part, i.e. the value of "Your puzzle answer was `...`".
+    /// Only present in descriptions downloaded after the part was solved.
+    pub fn confirmed_answer(&self, part: u8) -> Option<String> {
+        let text = self.part(part)?;
+        let rest = &text[text.find(ANSWER_MARKER)? + ANSWER_MARKER.len()..];
+        let line = rest.lines().next()?;
+        let value = strip_tags(line)
+            .trim()
+            .trim_end_matches('.')
+            .trim_matches('`')
+            .to_string();
+        (!value.is_empty()).then(|| decode_html(&value))
+    }
+
+    /// Returns the title from the `--- Day N: Title ---` heading.
+    pub fn title(&self) -> Option<String> {
+        let heading = self.parts.first()?.lines().find_map(|line| {
+            let line = strip_tags(line);
+            let line = line.trim().trim_start_matches('\\');
+            line.strip_prefix("--- Day ")
+                .and_then(|rest| rest.strip_suffix("---"))
+                .map(str::to_string)
+        })?;
+
+        let (_, title) = heading.split_once(':')?;
+        Some(decode_html(title.trim()))
+    }
+
+    /// Returns the prose of both parts as plain text, one string per paragraph.
+    /// Headings, code blocks and markup are left out, which makes the text suitable for searching.
+    pub fn plain_paragraphs(&self) -> Vec<String> {
+        self.parts
+            .iter()
+            .flat_map(|part| prose_paragraphs(part))
+            .map(|paragraph| plain_text(&paragraph))
+            .filter(|paragraph| !paragraph.is_empty() && !paragraph.starts_with("--- "))
+            .collect()
+    }
+}
+
+/// Splits a text into paragraphs, leaving out fenced code blocks.
+fn prose_paragraphs(text: &str) -> Vec<String> {
+    let mut paragraphs = vec![];
+    let mut current = String::new();
+    let mut in_fence = false;
+
+    for line in text.lines() {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push_str(line);
+            current.push('\n');
+        }
+    }
+
+    if !current.is_empty() {
+        paragraphs.push(current);
+    }
+
+    paragraphs
+}
+
+/// Extracts fenced markdown code blocks and `<pre><code>` html blocks.
+fn code_blocks(text: &str) -> Vec<String> {
+    let mut blocks: Vec<(usize, String)> = vec![];
+
+    let mut offset = 0;
+    let mut fence_start: Option<usize> = None;
+    let mut current = String::new();
+
+    for line in text.split_inclusive('\n') {
+        if line.trim_start().starts_with("```") {
+            match fence_start.take() {
+                Some(pos) => blocks.push((pos, std::mem::take(&mut current))),
+                None => fence_start = Some(offset),
+            }
+        } else if fence_start.is_some() {
+            current.push_str(line);
+        }
+        offset += line.len();
+    }
+
+    let mut rest = text;
+    let mut consumed = 0;
+    while let Some(start) = rest.find("<pre><code>") {
+        let body_start = start + "<pre><code>".len();
+        let Some(len) = rest[body_start..].find("</code></pre>") else {
+            break;
+        };
+        let body = &rest[body_start..body_start + len];
+        blocks.push((consumed + start, decode_html(&strip_tags(body))));
+        consumed += body_start + len;
+        rest = &rest[body_start + len..];
+    }
+
+    blocks.sort_by_key(|(pos, _)| *pos);
+    blocks.into_iter().map(|(_, block)| block).collect()
+}
+
+/// Returns all emphasized inline code values of a text, in order of appearance.
+fn emphasized_values(text: &str) -> Vec<String> {
+    let mut values = vec![];
+    let mut pos = 0;
+
+    while pos < text.len() {
+        let found = EMPHASIS_DELIMITERS.iter().find_map(|(open, close)| {
+            let body_start = pos + open.len();
+            if !text[pos..].starts_with(open) {
+                return None;
+            }
+            let len = text[body_start..].find(close)?;
+            Some((
+                &text[body_start..body_start + len],
+                body_start + len + close.len(),
+            ))
+        });
+
+        match found {
+            Some((value, end)) => {
+                let value = decode_html(value.trim());
+                if !value.is_empty() && !value.contains('\n') {
+                    values.push(value);
+                }
+                pos = end;
+            }
+            None => {
+                pos += text[pos..].chars().next().map_or(1, char::len_utf8);
+            }
+        }
+    }
+
+    values
+}
+
+/// Removes markdown and html markup of a paragraph and joins its lines.
+fn plain_text(paragraph: &str) -> String {
+    let text = decode_html(&strip_tags(paragraph));
+
+    // keep the text of links, drop their targets.
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text.as_str();
+    while let Some(pos) = rest.find("](") {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos + 2..];
+        rest = rest.find(')').map_or("", |end| &rest[end + 1..]);
+    }
+    out.push_str(rest);
+
+    out.lines()
+        .map(str::trim)
+        .filter(|line| !line.chars().all(|c| c == '-' || c == '='))
+        .flat_map(str::split_whitespace)
+        .map(|word| word.replace(['*', '`', '[', '\\'], ""))
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn strip_tags(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut in_tag = false;
+    for c in s.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => out.push(c),
+            _ => {}
+        }
+    }
+    out
+}
+
+fn decode_html(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{CodeBlock, Puzzle};
+
+    const MARKDOWN: &str = "\\--- Day 1: Chronal Calibration ---
+----------
+
+For example, if the device displays frequency changes of `+1, -2, +3, +1`:
+
+```
++1
+-2
++3
++1
+```
+
+In this example, the resulting frequency is `*3*`.
+
+\\--- Part Two ---
+----------
+
+For example, using the same list of changes above, the device would loop as follows:
+
+```
++1
+-1
+```
+
+Here are other examples:
+
+* `+3, +3, +4, -2, -4` first reaches `*10*` twice.
+* `-6, +3, +8, +5, -6` first reaches `*5*` twice.
+";
+
+    #[test]
+    fn reads_title() {
+        assert_eq!(
+            Puzzle::parse(MARKDOWN).title().as_deref(),
+            Some("Chronal Calibration")
+        );
+        assert_eq!(
+            Puzzle::parse("<h2>--- Day 13: Mine Cart Madness ---</h2>")
+                .title()
+                .as_deref(),
+            Some("Mine Cart Madness")
+        );
+        assert_eq!(Puzzle::parse("no heading").title(), None);
+    }
+
+    #[test]
+    fn extracts_plain_paragraphs() {
+        let puzzle = Puzzle::parse(
+            "\\--- Day 1: Test ---\n----------\n\nThe *carts* move [along](/2018/day/13) the `tracks`.\nThey turn.\n\n```\n/->-\\\n```\n",
+        );
+        assert_eq!(
+            puzzle.plain_paragraphs(),
+            vec!["The carts move along the tracks. They turn."]
+        );
+    }
+
+    #[test]
+    fn splits_parts() {
+        let puzzle = Puzzle::parse(MARKDOWN);
+        assert!(puzzle.part(1).unwrap().contains("Chronal Calibration"));
+        assert!(puzzle.part(2).unwrap().contains("Part Two"));
+        assert!(puzzle.part(3).is_none());
+    }
+
+    #[test]
+    fn extracts_fenced_code_blocks() {
+        let puzzle = Puzzle::parse(MARKDOWN);
+        assert_eq!(
+            puzzle.code_blocks(),
+            vec![
+                CodeBlock {
+                    part: 1,
+                    text: "+1\n-2\n+3\n+1\n".into()
+                },
+                CodeBlock {
+                    part: 2,
+                    text: "+1\n-1\n".into()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn extracts_html_code_blocks() {
+        let puzzle = Puzzle::parse("<pre><code>#.#\n&lt;<em>x</em>&gt;\n</code></pre>");
+        assert_eq!(puzzle.code_blocks()[0].text, "#.#\n<x>\n");
+    }
+
+    #[test]
+    fn finds_unambiguous_answer() {
+        let puzzle = Puzzle::parse(MARKDOWN);
+        let candidates = puzzle.answer_candidates(1);
+        assert_eq!(candidates.best, Some("3".into()));
+        assert!(!candidates.is_ambiguous());
+    }
+
+    #[test]
+    fn flags_ambiguous_answers() {
+        let puzzle = Puzzle::parse(MARKDOWN);
+        let candidates = puzzle.answer_candidates(2);
+        assert_eq!(candidates.values, vec!["10".to_string(), "5".to_string()]);
+        assert!(candidates.is_ambiguous());
+    }
+
+    #[test]
+    fn chooses_candidates_by_marker() {
+        let candidates = Puzzle::parse(MARKDOWN).answer_candidates(2);
+        assert_eq!(candidates.choose("#2"), Ok(Some("5".into())));
+        assert_eq!(candidates.choose("2"), Ok(Some("2".into())));
+        assert_eq!(candidates.choose(" 42 "), Ok(Some("42".into())));
+        assert_eq!(candidates.choose(""), Ok(None));
+        assert!(candidates.choose("#3").is_err());
+        assert!(candidates.choose("#0").is_err());
+    }
+
+    #[test]
+    fn reads_confirmed_answers() {
+        let puzzle = Puzzle::parse(
+            "In this example, the resulting frequency is `*3*`.\n\nYour puzzle answer was `1234`.\n\n\\--- Part Two ---\n----------\n\nYour puzzle answer was `-7`.\n",
+        );
+        assert_eq!(puzzle.confirmed_answer(1), Some("1234".into()));
+        assert_eq!(puzzle.confirmed_answer(2), Some("-7".into()));
+    }
+
+    #[test]
+    fn reads_html_confirmed_answers() {
+        let puzzle = Puzzle::parse("<p>Your puzzle answer was <code>33,45</code>.</p>");
+        assert_eq!(puzzle.confirmed_answer(1), Some("33,45".into()));
+        assert_eq!(puzzle.confirmed_answer(2), None);
+    }
+
+    #[test]
+    fn skips_unsolved_parts() {
+        let puzzle = Puzzle::parse(MARKDOWN);
+        assert_eq!(puzzle.confirmed_answer(1), None);
+        assert_eq!(puzzle.confirmed_answer(2), None);
+    }
+
+    #[test]
+    fn reads_html_emphasis() {
+        let puzzle = Puzzle::parse("<p>The answer is <code><em>7,3</em></code>.</p>");
+        assert_eq!(puzzle.answer_candidates(1).best, Some("7,3".into()));
+    }
+}
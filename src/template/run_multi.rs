@@ -1,40 +1,51 @@
-use std::{collections::HashSet, io};
+use std::{collections::BTreeSet, io};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{config, Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
-use super::{
-    all_days,
-    timings::{Timing, Timings},
-};
+use super::timings::{Timing, Timings};
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+/// The outcome of running several solutions.
+pub struct MultiRun {
+    /// Only collected for timed runs.
+    pub timings: Option<Timings>,
+    /// Days whose solution failed to build or exited with an error.
+    pub failed: Vec<Day>,
+}
+
+pub fn run_multi(
+    days_to_run: &BTreeSet<Day>,
+    is_release: bool,
+    is_timed: bool,
+) -> Result<MultiRun, Error> {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+    let mut failed: Vec<Day> = vec![];
 
     let mut need_space = false;
 
-    // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
-
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
-
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
-
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
-        });
+    // NOTE: the set yields non-duplicate, sorted day values.
+    for day in days_to_run.iter().copied() {
+        if need_space {
+            println!();
+        }
+        need_space = true;
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+
+        let (output, success) = child_commands::run_solution(day, is_timed, is_release)?;
 
-    if is_timed {
+        if !success {
+            println!("Failed.");
+            failed.push(day);
+        } else if output.is_empty() {
+            println!("Not solved.");
+        } else {
+            let val = child_commands::parse_exec_time(&output, day);
+            timings.push(val);
+        }
+    }
+
+    let timings = if is_timed {
         let timings = Timings { data: timings };
         let total_millis = timings.total_millis();
         println!(
@@ -43,7 +54,9 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
         Some(timings)
     } else {
         None
-    }
+    };
+
+    Ok(MultiRun { timings, failed })
 }
 
 #[derive(Debug)]
@@ -60,7 +73,7 @@ impl From<std::io::Error> for Error {
 
 #[must_use]
 pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+    config::get().bin_path(day)
 }
 
 /// All solutions live in isolated binaries.
@@ -75,11 +88,16 @@ pub mod child_commands {
         thread,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// Run the solution bin for a given day.
+    /// Returns the lines of its output and whether it succeeded.
+    pub fn run_solution(
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+    ) -> Result<(Vec<String>, bool), Error> {
         // skip command invocation for days that have not been scaffolded yet.
         if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+            return Ok((vec![], true));
         }
 
         let day_padded = day.to_string();
@@ -122,9 +140,9 @@ pub mod child_commands {
         }
 
         thread.join().unwrap();
-        cmd.wait()?;
+        let status = cmd.wait()?;
 
-        Ok(output)
+        Ok((output, status.success()))
     }
 
     pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
@@ -6,8 +6,13 @@ use std::process::Output;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
+use crate::template::commands::examples;
+use crate::template::git;
+use crate::template::hooks::{self, Hook};
+use crate::template::puzzle::Puzzle;
+use crate::template::stats::Verdict;
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{answers, aoc_cli, config, markdown, stats, Day, ANSI_ITALIC, ANSI_RESET};
 
 pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
     let part_str = format!("Part {part}");
@@ -18,13 +23,36 @@ pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, d
     print_result(&result, &part_str, &format_duration(&duration, samples));
 
     if let Some(result) = result {
-        submit_result(result, day, part);
+        let answer = result.to_string();
+        if let Some(Ok(output)) = submit_result(result, day, part) {
+            let verdict =
+                stats::record_submission(day, part, &String::from_utf8_lossy(&output.stdout));
+
+            hooks::run(
+                Hook::PostSubmit,
+                &hooks::Context {
+                    part: Some(part),
+                    answer: Some(answer),
+                    outcome: Some(verdict.as_str().into()),
+                    ..hooks::Context::day(day)
+                },
+            );
+
+            if verdict == Verdict::Correct && config::get().git.auto_commit {
+                git::commit_solution(day, part);
+            }
+
+            if part == 1 && verdict == Verdict::Correct {
+                refresh_puzzle(day);
+            }
+        }
     }
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///     The limits are set in the `benchmark` section of the project configuration.
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
@@ -58,11 +86,13 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
+    let settings = &config::get().benchmark;
     let bench_iterations = cmp::min(
-        10000,
+        settings.max_samples,
         cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
+            Duration::from_millis(1).as_nanos() * settings.target_millis
+                / cmp::max(base_time.as_nanos(), 10),
+            settings.min_samples,
         ),
     );
 
@@ -172,3 +202,37 @@ fn submit_result<T: Display>(
     println!("Submitting result via aoc-cli...");
     Some(aoc_cli::submit(day, part, &result.to_string()))
 }
+
+/// Part two is only revealed once part one is accepted:
+/// re-fetch the puzzle description (leaving the input untouched), show part two and extract its examples.
+fn refresh_puzzle(day: Day) {
+    if let Err(e) = aoc_cli::download_puzzle(day) {
+        eprintln!("Failed to refresh the puzzle description: {e}");
+        return;
+    }
+
+    let puzzle = match Puzzle::read(day) {
+        Ok(puzzle) => puzzle,
+        Err(e) => {
+            eprintln!("Failed to read the puzzle description: {e}");
+            return;
+        }
+    };
+
+    answers::harvest(day);
+
+    match puzzle.part(2) {
+        Some(part_two) => {
+            println!();
+            println!("{}", markdown::render(part_two, markdown::terminal_width()));
+        }
+        None => {
+            println!("The puzzle description does not contain part two yet. Try `cargo read {day}` later.");
+            return;
+        }
+    }
+
+    if let Err(e) = examples::extract(day, &puzzle, false) {
+        eprintln!("Failed to update examples: {e}");
+    }
+}
@@ -0,0 +1,242 @@
+/// Module that searches the text of downloaded puzzle descriptions.
+use crate::template::{all_days, puzzle::Puzzle, Day, ANSI_BOLD, ANSI_RESET};
+
+/// How many characters of context to show on each side of a match.
+const SNIPPET_RADIUS: usize = 50;
+const MAX_SNIPPETS: usize = 3;
+
+/// The searchable text of one downloaded puzzle.
+pub struct Document {
+    pub day: Day,
+    pub title: Option<String>,
+    pub paragraphs: Vec<String>,
+}
+
+impl Document {
+    pub fn new(day: Day, puzzle: &Puzzle) -> Self {
+        Self {
+            day,
+            title: puzzle.title(),
+            paragraphs: puzzle.plain_paragraphs(),
+        }
+    }
+}
+
+/// A puzzle that matches all search terms.
+pub struct Hit<'a> {
+    pub document: &'a Document,
+    pub score: usize,
+    /// Paragraph excerpts around the matches, with the terms highlighted.
+    pub snippets: Vec<String>,
+}
+
+/// Reads all downloaded puzzle descriptions.
+pub fn read_documents() -> Vec<Document> {
+    all_days()
+        .filter_map(|day| Puzzle::read(day).ok().map(|p| Document::new(day, &p)))
+        .collect()
+}
+
+/// Splits a query into lowercase terms.
+pub fn terms(query: &str) -> Vec<String> {
+    query
+        .split_whitespace()
+        .map(str::to_ascii_lowercase)
+        .collect()
+}
+
+/// Returns the documents that contain every term, best matches first.
+/// Matches in the title weigh more than matches in the text.
+pub fn search<'a>(documents: &'a [Document], terms: &[String]) -> Vec<Hit<'a>> {
+    if terms.is_empty() {
+        return vec![];
+    }
+
+    let mut hits: Vec<Hit> = documents
+        .iter()
+        .filter_map(|document| {
+            let title = document
+                .title
+                .as_deref()
+                .unwrap_or_default()
+                .to_ascii_lowercase();
+            let paragraphs: Vec<String> = document
+                .paragraphs
+                .iter()
+                .map(|p| p.to_ascii_lowercase())
+                .collect();
+
+            let mut score = 0;
+            for term in terms {
+                let in_title = title.matches(term.as_str()).count();
+                let in_text: usize = paragraphs
+                    .iter()
+                    .map(|p| p.matches(term.as_str()).count())
+                    .sum();
+                if in_title + in_text == 0 {
+                    return None;
+                }
+                score += in_title * 10 + in_text;
+            }
+
+            let snippets = document
+                .paragraphs
+                .iter()
+                .filter_map(|paragraph| snippet(paragraph, terms))
+                .take(MAX_SNIPPETS)
+                .collect();
+
+            Some(Hit {
+                document,
+                score,
+                snippets,
+            })
+        })
+        .collect();
+
+    hits.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then(a.document.day.cmp(&b.document.day))
+    });
+    hits
+}
+
+/// Cuts the text around the first match of any term and highlights all terms in it.
+fn snippet(paragraph: &str, terms: &[String]) -> Option<String> {
+    let lower = paragraph.to_ascii_lowercase();
+    let first = terms.iter().filter_map(|t| lower.find(t.as_str())).min()?;
+
+    let mut start = first.saturating_sub(SNIPPET_RADIUS);
+    while !paragraph.is_char_boundary(start) {
+        start -= 1;
+    }
+    let mut end = (first + SNIPPET_RADIUS).min(paragraph.len());
+    while !paragraph.is_char_boundary(end) {
+        end += 1;
+    }
+
+    // do not cut words in half.
+    if start > 0 {
+        start = paragraph[start..first]
+            .find(' ')
+            .map_or(start, |pos| start + pos + 1);
+    }
+    if end < paragraph.len() {
+        end = paragraph[first..end]
+            .rfind(' ')
+            .map_or(end, |pos| (first + pos).max(first + 1));
+        while !paragraph.is_char_boundary(end) {
+            end += 1;
+        }
+    }
+
+    let mut out = String::new();
+    if start > 0 {
+        out.push('…');
+    }
+    out.push_str(&highlight(&paragraph[start..end], terms));
+    if end < paragraph.len() {
+        out.push('…');
+    }
+    Some(out)
+}
+
+/// Wraps every occurrence of a term in bold.
+fn highlight(text: &str, terms: &[String]) -> String {
+    let lower = text.to_ascii_lowercase();
+    let mut out = String::with_capacity(text.len());
+    let mut pos = 0;
+
+    while pos < text.len() {
+        let matched = terms
+            .iter()
+            .filter(|t| !t.is_empty() && lower[pos..].starts_with(t.as_str()))
+            .map(String::len)
+            .max();
+
+        match matched {
+            Some(len) => {
+                out.push_str(ANSI_BOLD);
+                out.push_str(&text[pos..pos + len]);
+                out.push_str(ANSI_RESET);
+                pos += len;
+            }
+            None => {
+                let len = text[pos..].chars().next().map_or(1, char::len_utf8);
+                out.push_str(&text[pos..pos + len]);
+                pos += len;
+            }
+        }
+    }
+
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{highlight, search, snippet, terms, Document};
+    use crate::{
+        day,
+        template::{ANSI_BOLD, ANSI_RESET},
+    };
+
+    fn documents() -> Vec<Document> {
+        vec![
+            Document {
+                day: day!(3),
+                title: Some("No Matter How You Slice It".into()),
+                paragraphs: vec!["The elves cut fabric into claims, madness.".into()],
+            },
+            Document {
+                day: day!(13),
+                title: Some("Mine Cart Madness".into()),
+                paragraphs: vec![
+                    "Several carts drive along the tracks.".into(),
+                    "Carts turn at intersections.".into(),
+                ],
+            },
+        ]
+    }
+
+    #[test]
+    fn finds_documents_with_all_terms() {
+        let documents = documents();
+
+        let hits = search(&documents, &terms("Carts tracks"));
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].document.day, day!(13));
+        assert_eq!(hits[0].snippets.len(), 2);
+
+        assert!(search(&documents, &terms("carts fabric")).is_empty());
+        assert!(search(&documents, &terms("")).is_empty());
+    }
+
+    #[test]
+    fn ranks_title_matches_first() {
+        let documents = documents();
+        let hits = search(&documents, &terms("madness"));
+        assert_eq!(hits[0].document.day, day!(13));
+        assert_eq!(hits[1].document.day, day!(3));
+    }
+
+    #[test]
+    fn highlights_terms() {
+        assert_eq!(
+            highlight("Carts and carts", &terms("cart")),
+            format!("{ANSI_BOLD}Cart{ANSI_RESET}s and {ANSI_BOLD}cart{ANSI_RESET}s")
+        );
+    }
+
+    #[test]
+    fn cuts_long_paragraphs() {
+        let paragraph = format!("{} needle {}", "a ".repeat(100), "b ".repeat(100));
+        let snippet = snippet(&paragraph, &terms("needle")).unwrap();
+        assert!(snippet.starts_with('…'));
+        assert!(snippet.ends_with('…'));
+        assert!(snippet.contains(&format!("{ANSI_BOLD}needle{ANSI_RESET}")));
+        assert!(snippet.len() < paragraph.len());
+    }
+}
@@ -0,0 +1,232 @@
+/// Module that parses the days a command should act on, e.g. `5`, `11-16`, `1,3,5`, `..10`, `unsolved` or `failing`.
+use std::{collections::BTreeSet, error::Error, fmt::Display, path::Path, str::FromStr};
+
+use crate::template::{all_days, answers::Answers, config, stats::Stats, Day};
+
+/// A set of days, given as a list of days and ranges or as a keyword that is resolved from the stored state.
+/// Keywords only select days that are scaffolded, i.e. have a solution in the bin folder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DaySelection {
+    Days(BTreeSet<Day>),
+    /// Days that do not have both parts solved yet.
+    Unsolved,
+    /// Days with an unsolved part for which a wrong answer was submitted.
+    Failing,
+}
+
+impl DaySelection {
+    pub fn all() -> Self {
+        DaySelection::Days(all_days().collect())
+    }
+
+    /// Returns the day if the selection is exactly one day.
+    pub fn single(&self) -> Option<Day> {
+        match self {
+            DaySelection::Days(days) if days.len() == 1 => days.first().copied(),
+            _ => None,
+        }
+    }
+
+    /// Returns the selected days in ascending order.
+    pub fn days(&self) -> BTreeSet<Day> {
+        match self {
+            DaySelection::Days(days) => days.clone(),
+            _ => self.resolve(
+                &Answers::read_from_file(),
+                &Stats::read_from_file(),
+                |day| Path::new(&config::get().bin_path(day)).exists(),
+            ),
+        }
+    }
+
+    fn resolve(
+        &self,
+        answers: &Answers,
+        stats: &Stats,
+        is_scaffolded: impl Fn(Day) -> bool,
+    ) -> BTreeSet<Day> {
+        let is_solved = |day: Day, part: u8| {
+            answers.get(day, part).is_some()
+                || stats.get(day).is_some_and(|s| s.solved_at(part).is_some())
+        };
+
+        match self {
+            DaySelection::Days(days) => days.clone(),
+            DaySelection::Unsolved => all_days()
+                .filter(|day| is_scaffolded(*day))
+                .filter(|day| !is_solved(*day, 1) || !is_solved(*day, 2))
+                .collect(),
+            DaySelection::Failing => all_days()
+                .filter(|day| is_scaffolded(*day))
+                .filter(|day| {
+                    (1..=2).any(|part| {
+                        !is_solved(*day, part)
+                            && stats.get(*day).is_some_and(|s| s.wrong_attempts(part) > 0)
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = DaySelectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "all" => return Ok(DaySelection::all()),
+            "unsolved" => return Ok(DaySelection::Unsolved),
+            "failing" => return Ok(DaySelection::Failing),
+            _ => {}
+        }
+
+        let mut days = BTreeSet::new();
+
+        for item in s.split(',').map(str::trim) {
+            let (start, end) = if let Some((start, end)) = item.split_once("..") {
+                (parse_bound(start, 1)?, parse_bound(end, 25)?)
+            } else if let Some((start, end)) = item.split_once('-') {
+                (parse_day(start)?, parse_day(end)?)
+            } else {
+                let day = parse_day(item)?;
+                (day, day)
+            };
+
+            if start > end {
+                return Err(DaySelectionError);
+            }
+
+            days.extend(all_days().filter(|day| *day >= start && *day <= end));
+        }
+
+        Ok(DaySelection::Days(days))
+    }
+}
+
+fn parse_day(s: &str) -> Result<Day, DaySelectionError> {
+    s.trim().parse().map_err(|_| DaySelectionError)
+}
+
+/// Parses one end of an open range like `..10` or `20..`, which defaults to the first or last day.
+fn parse_bound(s: &str, default: u8) -> Result<Day, DaySelectionError> {
+    if s.trim().is_empty() {
+        Day::new(default).ok_or(DaySelectionError)
+    } else {
+        parse_day(s)
+    }
+}
+
+/// An error which can be returned when parsing a [`DaySelection`].
+#[derive(Debug)]
+pub struct DaySelectionError;
+
+impl Error for DaySelectionError {}
+
+impl Display for DaySelectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(
+            "expecting days between 1 and 25, e.g. `5`, `11-16`, `1,3,5`, `..10`, `unsolved` or `failing`",
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::DaySelection;
+    use crate::{
+        day,
+        template::{
+            answers::Answers,
+            stats::{Attempt, Stats, Verdict},
+            Day,
+        },
+    };
+
+    fn days(s: &str) -> Vec<Day> {
+        s.parse::<DaySelection>()
+            .unwrap()
+            .days()
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn parses_days_and_ranges() {
+        assert_eq!(days("7"), vec![day!(7)]);
+        assert_eq!(days("3-5"), vec![day!(3), day!(4), day!(5)]);
+        assert_eq!(days("5,1,3"), vec![day!(1), day!(3), day!(5)]);
+        assert_eq!(days("1, 3-4,3"), vec![day!(1), day!(3), day!(4)]);
+        assert_eq!(days("..3"), vec![day!(1), day!(2), day!(3)]);
+        assert_eq!(days("24.."), vec![day!(24), day!(25)]);
+        assert_eq!(days("2..3"), vec![day!(2), day!(3)]);
+        assert_eq!(days("all").len(), 25);
+    }
+
+    #[test]
+    fn rejects_invalid_selections() {
+        for s in ["", "5-3", "0-3", "1-26", "1,,2", "..26", "unknown"] {
+            assert!(s.parse::<DaySelection>().is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn detects_single_days() {
+        assert_eq!("7".parse::<DaySelection>().unwrap().single(), Some(day!(7)));
+        assert_eq!("7-8".parse::<DaySelection>().unwrap().single(), None);
+        assert_eq!(DaySelection::Unsolved.single(), None);
+    }
+
+    #[test]
+    fn resolves_unsolved_and_failing_days() {
+        let mut answers = Answers::default();
+        answers.set(day!(1), 1, "1");
+        answers.set(day!(1), 2, "2");
+        answers.set(day!(2), 1, "3");
+
+        let mut stats = Stats::default();
+        stats.record(
+            day!(3),
+            Attempt {
+                part: 1,
+                timestamp: 0,
+                verdict: Verdict::Incorrect,
+            },
+        );
+        stats.record(
+            day!(4),
+            Attempt {
+                part: 1,
+                timestamp: 0,
+                verdict: Verdict::Incorrect,
+            },
+        );
+        stats.record(
+            day!(4),
+            Attempt {
+                part: 1,
+                timestamp: 1,
+                verdict: Verdict::Correct,
+            },
+        );
+
+        let unsolved = DaySelection::Unsolved.resolve(&answers, &stats, |_| true);
+        assert!(!unsolved.contains(&day!(1)));
+        assert!(unsolved.contains(&day!(2)));
+        assert_eq!(unsolved.len(), 24);
+
+        let failing = DaySelection::Failing.resolve(&answers, &stats, |_| true);
+        assert_eq!(failing.into_iter().collect::<Vec<_>>(), vec![day!(3)]);
+
+        // days without a solution are never selected by a keyword.
+        let scaffolded = |day: Day| day.into_inner() <= 3;
+        let unsolved = DaySelection::Unsolved.resolve(&answers, &stats, scaffolded);
+        assert_eq!(
+            unsolved.into_iter().collect::<Vec<_>>(),
+            vec![day!(2), day!(3)]
+        );
+        let failing = DaySelection::Failing.resolve(&answers, &stats, |_| false);
+        assert!(failing.is_empty());
+    }
+}
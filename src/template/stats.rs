@@ -0,0 +1,568 @@
+/// Module that records personal solve statistics: when a day was started and every submission made for it.
+use std::{
+    collections::HashMap,
+    fmt::Write as _,
+    fs,
+    io::Error,
+    path::Path,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{
+    all_days, config, leaderboard::format_duration, Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+
+fn get_file_path() -> String {
+    config::get().account_data_file("stats.json")
+}
+
+/// Returns the current time as a unix timestamp.
+pub fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| i64::try_from(d.as_secs()).unwrap_or(i64::MAX))
+}
+
+/// Outcome of a submission, as reported by the site.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    /// The answer was submitted too soon after a previous wrong answer.
+    TooRecent,
+    /// The part was already solved, so the answer was not checked.
+    AlreadySolved,
+    Unknown,
+}
+
+impl Verdict {
+    /// Detects the verdict from the message printed by aoc-cli after submitting.
+    pub fn parse(message: &str) -> Self {
+        if message.contains("That's the right answer") {
+            Verdict::Correct
+        } else if message.contains("That's not the right answer") {
+            Verdict::Incorrect
+        } else if message.contains("You gave an answer too recently") {
+            Verdict::TooRecent
+        } else if message.contains("Did you already complete it") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Incorrect => "incorrect",
+            Verdict::TooRecent => "too_recent",
+            Verdict::AlreadySolved => "already_solved",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "incorrect" => Ok(Verdict::Incorrect),
+            "too_recent" => Ok(Verdict::TooRecent),
+            "already_solved" => Ok(Verdict::AlreadySolved),
+            "unknown" => Ok(Verdict::Unknown),
+            s => Err(format!("unknown verdict `{s}`.")),
+        }
+    }
+}
+
+/// A single submission of an answer.
+#[derive(Clone, Debug)]
+pub struct Attempt {
+    pub part: u8,
+    pub timestamp: i64,
+    pub verdict: Verdict,
+}
+
+/// Represents the solve history of a single day.
+#[derive(Clone, Debug)]
+pub struct DayStats {
+    pub day: Day,
+    /// When the day was scaffolded or first opened.
+    pub started: Option<i64>,
+    pub attempts: Vec<Attempt>,
+}
+
+impl DayStats {
+    /// Returns the timestamp of the first correct submission of a part.
+    pub fn solved_at(&self, part: u8) -> Option<i64> {
+        self.attempts
+            .iter()
+            .find(|a| a.part == part && a.verdict == Verdict::Correct)
+            .map(|a| a.timestamp)
+    }
+
+    /// Number of wrong answers submitted for a part before it was solved.
+    pub fn wrong_attempts(&self, part: u8) -> usize {
+        let solved_at = self.solved_at(part).unwrap_or(i64::MAX);
+        self.attempts
+            .iter()
+            .filter(|a| a.part == part && a.verdict == Verdict::Incorrect)
+            .filter(|a| a.timestamp <= solved_at)
+            .count()
+    }
+
+    /// Seconds from starting the day to solving a part.
+    pub fn time_to_solve(&self, part: u8) -> Option<i64> {
+        Some(self.solved_at(part)? - self.started?)
+    }
+
+    /// Seconds from solving part one to solving part two.
+    pub fn time_between_parts(&self) -> Option<i64> {
+        Some(self.solved_at(2)? - self.solved_at(1)?)
+    }
+}
+
+/// Represents the solve history for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Stats {
+    pub data: Vec<DayStats>,
+}
+
+impl Stats {
+    /// Dehydrate stats to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        config::store_json(&get_file_path(), &JsonValue::from(self.clone()))
+    }
+
+    /// Rehydrate stats from a JSON file. If not present, returns empty stats.
+    pub fn read_from_file() -> Self {
+        let path = get_file_path();
+        if !Path::new(&path).exists() {
+            return Stats::default();
+        }
+
+        let s = fs::read_to_string(&path)
+            .map_err(|x| x.to_string())
+            .and_then(Stats::try_from);
+
+        match s {
+            Ok(stats) => stats,
+            Err(e) => {
+                eprintln!("{e}");
+                Stats::default()
+            }
+        }
+    }
+
+    pub fn get(&self, day: Day) -> Option<&DayStats> {
+        self.data.iter().find(|d| d.day == day)
+    }
+
+    fn get_mut(&mut self, day: Day) -> &mut DayStats {
+        let index = match self.data.iter().position(|d| d.day == day) {
+            Some(index) => index,
+            None => {
+                self.data.push(DayStats {
+                    day,
+                    started: None,
+                    attempts: vec![],
+                });
+                self.data.sort_unstable_by_key(|d| d.day);
+                self.data.iter().position(|d| d.day == day).unwrap()
+            }
+        };
+        &mut self.data[index]
+    }
+
+    /// Records the start of a day, unless it was started before. Returns `true` if it was recorded.
+    pub fn start(&mut self, day: Day, timestamp: i64) -> bool {
+        let stats = self.get_mut(day);
+        if stats.started.is_some() {
+            return false;
+        }
+        stats.started = Some(timestamp);
+        true
+    }
+
+    pub fn record(&mut self, day: Day, attempt: Attempt) {
+        self.get_mut(day).attempts.push(attempt);
+    }
+}
+
+/// Records that a day was scaffolded or opened, if this is the first time.
+pub fn mark_started(day: Day) {
+    let mut stats = Stats::read_from_file();
+    if stats.start(day, now()) {
+        if let Err(e) = stats.store_file() {
+            eprintln!("Failed to store solve stats: {e}");
+        }
+    }
+}
+
+/// Records a submission with the verdict detected from the aoc-cli output.
+pub fn record_submission(day: Day, part: u8, message: &str) -> Verdict {
+    let verdict = Verdict::parse(message);
+
+    let mut stats = Stats::read_from_file();
+    stats.record(
+        day,
+        Attempt {
+            part,
+            timestamp: now(),
+            verdict,
+        },
+    );
+
+    if let Err(e) = stats.store_file() {
+        eprintln!("Failed to store solve stats: {e}");
+    }
+
+    verdict
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Renders time-to-solve per part, time between parts and wrong attempts for every started day.
+pub fn render_table(stats: &Stats) -> String {
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "{:<6}  {:>8}  {:>8}  {:>8}  {:>5}",
+        "", "Part 1", "Part 2", "Delta", "Wrong"
+    );
+
+    let format = |t: Option<i64>| t.map_or_else(|| "-".into(), format_duration);
+
+    for day_stats in &stats.data {
+        let wrong = (day_stats.wrong_attempts(1), day_stats.wrong_attempts(2));
+
+        let _ = writeln!(
+            out,
+            "Day {}  {ANSI_BOLD}{:>8}{ANSI_RESET}  {ANSI_BOLD}{:>8}{ANSI_RESET}  {ANSI_ITALIC}{:>8}{ANSI_RESET}  {:>5}",
+            day_stats.day,
+            format(day_stats.time_to_solve(1)),
+            format(day_stats.time_to_solve(2)),
+            format(day_stats.time_between_parts()),
+            format!("{}/{}", wrong.0, wrong.1),
+        );
+    }
+
+    out
+}
+
+/// Renders a bar per day of the event showing the time it took to finish the day.
+pub fn render_distribution(stats: &Stats, width: usize) -> String {
+    let times: Vec<(Day, Option<i64>)> = all_days()
+        .map(|day| {
+            let time = stats
+                .get(day)
+                .and_then(|d| d.time_to_solve(2).or_else(|| d.time_to_solve(1)));
+            (day, time)
+        })
+        .collect();
+
+    let max = times.iter().filter_map(|(_, t)| *t).max().unwrap_or(0);
+    let bar_width = width.saturating_sub(18).max(1);
+
+    let mut out = String::new();
+    for (day, time) in times {
+        let Some(time) = time else {
+            let _ = writeln!(out, "Day {day}  {:>8}", "-");
+            continue;
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let len = if max == 0 {
+            0
+        } else {
+            (time as f64 / max as f64 * bar_width as f64).round() as usize
+        };
+
+        let _ = writeln!(
+            out,
+            "Day {day}  {:>8}  {}",
+            format_duration(time),
+            "█".repeat(len.max(1))
+        );
+    }
+
+    out
+}
+
+/// Renders totals over all days: solved parts, wrong attempts and the average time per part.
+pub fn render_summary(stats: &Stats) -> String {
+    let mut out = String::new();
+
+    for part in 1..=2 {
+        let times: Vec<i64> = stats
+            .data
+            .iter()
+            .filter_map(|d| d.time_to_solve(part))
+            .collect();
+        let solved = stats
+            .data
+            .iter()
+            .filter(|d| d.solved_at(part).is_some())
+            .count();
+        let wrong: usize = stats.data.iter().map(|d| d.wrong_attempts(part)).sum();
+
+        let average = if times.is_empty() {
+            "-".into()
+        } else {
+            format_duration(times.iter().sum::<i64>() / times.len() as i64)
+        };
+
+        let _ = writeln!(
+            out,
+            "{ANSI_BOLD}Part {part}:{ANSI_RESET} {solved} solved, {wrong} wrong attempts, {average} on average"
+        );
+    }
+
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Stats> for JsonValue {
+    fn from(value: Stats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Stats {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Stats {
+            data: json_data
+                .iter()
+                .map(DayStats::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&DayStats> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &DayStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert(
+            "started".into(),
+            value
+                .started
+                .map_or(JsonValue::Null, |t| JsonValue::Number(t as f64)),
+        );
+        map.insert(
+            "attempts".into(),
+            JsonValue::Array(value.attempts.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for DayStats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected stats.day to be a Day struct.")?;
+
+        let started = json
+            .get("started")
+            .map(|v| {
+                if v.is_null() {
+                    None
+                } else {
+                    v.get::<f64>().map(|t| *t as i64)
+                }
+            })
+            .ok_or("Expected stats.started to be null or number.")?;
+
+        let attempts = json
+            .get("attempts")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .ok_or("Expected stats.attempts to be an array.")?
+            .iter()
+            .map(Attempt::try_from)
+            .collect::<Result<_, _>>()?;
+
+        Ok(DayStats {
+            day,
+            started,
+            attempts,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Attempt> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &Attempt) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(value.verdict.as_str().into()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Attempt {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected attempt to be a JSON object.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|p| *p as u8)
+            .filter(|p| (1..=2).contains(p))
+            .ok_or("Expected attempt.part to be 1 or 2.")?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|t| *t as i64)
+            .ok_or("Expected attempt.timestamp to be a number.")?;
+
+        let verdict = json
+            .get("verdict")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected attempt.verdict to be a string.")?
+            .parse()?;
+
+        Ok(Attempt {
+            part,
+            timestamp,
+            verdict,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Attempt, Stats, Verdict};
+    use crate::day;
+
+    fn attempt(part: u8, timestamp: i64, verdict: Verdict) -> Attempt {
+        Attempt {
+            part,
+            timestamp,
+            verdict,
+        }
+    }
+
+    fn fixture() -> Stats {
+        let mut stats = Stats::default();
+        stats.start(day!(2), 1000);
+        stats.record(day!(2), attempt(1, 1300, Verdict::Incorrect));
+        stats.record(day!(2), attempt(1, 1400, Verdict::TooRecent));
+        stats.record(day!(2), attempt(1, 1600, Verdict::Correct));
+        stats.record(day!(2), attempt(2, 2200, Verdict::Correct));
+        stats.record(day!(2), attempt(2, 2300, Verdict::AlreadySolved));
+        stats
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        assert_eq!(
+            Verdict::parse("That's the right answer! You are one gold star closer."),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer; your answer is too low."),
+            Verdict::Incorrect
+        );
+        assert_eq!(
+            Verdict::parse("You gave an answer too recently; you have to wait."),
+            Verdict::TooRecent
+        );
+        assert_eq!(
+            Verdict::parse(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Verdict::AlreadySolved
+        );
+        assert_eq!(Verdict::parse(""), Verdict::Unknown);
+    }
+
+    #[test]
+    fn computes_times() {
+        let stats = fixture();
+        let day = stats.get(day!(2)).unwrap();
+        assert_eq!(day.time_to_solve(1), Some(600));
+        assert_eq!(day.time_to_solve(2), Some(1200));
+        assert_eq!(day.time_between_parts(), Some(600));
+        assert_eq!(day.wrong_attempts(1), 1);
+        assert_eq!(day.wrong_attempts(2), 0);
+    }
+
+    #[test]
+    fn keeps_first_start() {
+        let mut stats = fixture();
+        assert!(!stats.start(day!(2), 5000));
+        assert!(stats.start(day!(1), 5000));
+        assert_eq!(stats.data[0].day, day!(1));
+        assert_eq!(stats.get(day!(2)).unwrap().started, Some(1000));
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let json = tinyjson::JsonValue::from(fixture()).stringify().unwrap();
+        let parsed = Stats::try_from(json).unwrap();
+        let day = parsed.get(day!(2)).unwrap();
+        assert_eq!(day.started, Some(1000));
+        assert_eq!(day.attempts.len(), 5);
+        assert_eq!(day.attempts[1].verdict, Verdict::TooRecent);
+        assert_eq!(day.solved_at(2), Some(2200));
+    }
+}
@@ -0,0 +1,189 @@
+/// Module that collects the progress of every day: code, data files, tests, answers and timings.
+use std::{fmt::Write as _, fs, path::Path};
+
+use crate::template::{
+    answers::Answers, aoc_cli, config, run_multi::get_path_for_bin, stats::Stats, timings::Timings,
+    Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+
+/// Macros that mark code that still has to be written.
+const UNFINISHED_MARKERS: [&str; 2] = ["todo!(", "unimplemented!("];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    Open,
+    /// Only wrong answers were submitted so far.
+    Attempted,
+    Solved,
+}
+
+#[derive(Clone, Debug)]
+pub struct DayStatus {
+    pub day: Day,
+    pub scaffolded: bool,
+    pub input: bool,
+    pub examples: bool,
+    /// `None` if the tests were not run.
+    pub tests: Option<bool>,
+    pub parts: [PartStatus; 2],
+    pub timed: bool,
+    /// Line numbers of unfinished code, e.g. a `todo!()`.
+    pub unfinished: Vec<usize>,
+}
+
+impl DayStatus {
+    pub fn collect(day: Day, answers: &Answers, stats: &Stats, timings: &Timings) -> Self {
+        let source = fs::read_to_string(get_path_for_bin(day)).ok();
+
+        let part = |part: u8| {
+            let day_stats = stats.get(day);
+            if answers.get(day, part).is_some()
+                || day_stats.is_some_and(|s| s.solved_at(part).is_some())
+            {
+                PartStatus::Solved
+            } else if day_stats.is_some_and(|s| s.wrong_attempts(part) > 0) {
+                PartStatus::Attempted
+            } else {
+                PartStatus::Open
+            }
+        };
+
+        Self {
+            day,
+            scaffolded: source.is_some(),
+            input: is_non_empty(&aoc_cli::get_input_path(day)),
+            examples: is_non_empty(&config::get().example_path(day)),
+            tests: None,
+            parts: [part(1), part(2)],
+            timed: timings.data.iter().any(|t| t.day == day),
+            unfinished: source.as_deref().map(find_unfinished).unwrap_or_default(),
+        }
+    }
+
+    /// Whether there is nothing left to do for this day.
+    pub fn is_done(&self) -> bool {
+        self.parts == [PartStatus::Solved; 2]
+            && self.unfinished.is_empty()
+            && self.tests != Some(false)
+    }
+}
+
+fn is_non_empty(path: &str) -> bool {
+    fs::read_to_string(Path::new(path)).is_ok_and(|s| !s.trim().is_empty())
+}
+
+/// Returns the (1-based) line numbers that contain an unfinished marker.
+pub fn find_unfinished(source: &str) -> Vec<usize> {
+    source
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| {
+            let code = line.split("//").next().unwrap_or_default();
+            UNFINISHED_MARKERS.iter().any(|m| code.contains(m))
+        })
+        .map(|(i, _)| i + 1)
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Renders a row per day with a column per item.
+pub fn render(days: &[DayStatus]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "{:<6}  {:^4}  {:^5}  {:^8}  {:^5}  {:^6}  {:^6}  {:^5}  Notes",
+        "", "Code", "Input", "Examples", "Tests", "Part 1", "Part 2", "Timed"
+    );
+
+    let check = |b: bool| if b { "✔" } else { "-" };
+    let part = |p: PartStatus| match p {
+        PartStatus::Open => "-",
+        PartStatus::Attempted => "✖",
+        PartStatus::Solved => "★",
+    };
+
+    for status in days {
+        let tests = match status.tests {
+            None => " ",
+            Some(true) => "✔",
+            Some(false) => "✖",
+        };
+
+        let notes = if status.unfinished.is_empty() {
+            String::new()
+        } else {
+            let lines: Vec<String> = status.unfinished.iter().map(ToString::to_string).collect();
+            format!(
+                "{ANSI_ITALIC}todo in {}:{}{ANSI_RESET}",
+                get_path_for_bin(status.day),
+                lines.join(",")
+            )
+        };
+
+        let (bold, reset) = if status.is_done() {
+            (ANSI_BOLD, ANSI_RESET)
+        } else {
+            ("", "")
+        };
+
+        let _ = writeln!(
+            out,
+            "{bold}Day {}{reset}  {:^4}  {:^5}  {:^8}  {:^5}  {:^6}  {:^6}  {:^5}  {notes}",
+            status.day,
+            check(status.scaffolded),
+            check(status.input),
+            check(status.examples),
+            tests,
+            part(status.parts[0]),
+            part(status.parts[1]),
+            check(status.timed),
+        );
+    }
+
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{find_unfinished, DayStatus, PartStatus};
+    use crate::{
+        day,
+        template::{
+            answers::Answers,
+            stats::{Attempt, Stats, Verdict},
+            timings::Timings,
+        },
+    };
+
+    #[test]
+    fn finds_unfinished_code() {
+        let source =
+            "fn a() {\n    todo!()\n}\n// todo!() in a comment\nfn b() { unimplemented!(\"b\") }\n";
+        assert_eq!(find_unfinished(source), vec![2, 5]);
+        assert!(find_unfinished("fn a() -> Option<u32> { None }").is_empty());
+    }
+
+    #[test]
+    fn collects_part_status() {
+        let mut answers = Answers::default();
+        answers.set(day!(24), 1, "42");
+
+        let mut stats = Stats::default();
+        stats.record(
+            day!(24),
+            Attempt {
+                part: 2,
+                timestamp: 0,
+                verdict: Verdict::Incorrect,
+            },
+        );
+
+        let status = DayStatus::collect(day!(24), &answers, &stats, &Timings::default());
+        assert_eq!(status.parts, [PartStatus::Solved, PartStatus::Attempted]);
+        assert!(!status.timed);
+        assert!(!status.is_done());
+    }
+}
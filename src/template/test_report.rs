@@ -0,0 +1,240 @@
+/// Module that parses the output of `cargo test` for a day into a result per test.
+use std::fmt::Write as _;
+
+use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// The message of `read_file` when a file does not exist.
+pub const MISSING_FILE_MESSAGE: &str = "could not open input file";
+/// Printed by `read_file` when a file is empty.
+pub const EMPTY_FILE_MESSAGE: &str = "input file is empty";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Passed,
+    Failed {
+        /// The right side of a failed `assert_eq!`, the expected value in the scaffolded tests.
+        expected: Option<String>,
+        /// The left side of a failed `assert_eq!`, the result of the solution.
+        actual: Option<String>,
+        /// The first line of the panic message.
+        message: String,
+    },
+    /// The test can not run because its example is missing or empty.
+    Skipped(String),
+    Ignored,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TestCase {
+    pub name: String,
+    pub outcome: Outcome,
+}
+
+impl TestCase {
+    /// The part a test belongs to, e.g. `1` for `test_part_one`.
+    pub fn part(&self) -> Option<u8> {
+        let name = self.name.to_ascii_lowercase();
+        if ["part_one", "part_1", "part1"]
+            .iter()
+            .any(|p| name.contains(p))
+        {
+            Some(1)
+        } else if ["part_two", "part_2", "part2"]
+            .iter()
+            .any(|p| name.contains(p))
+        {
+            Some(2)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parses the (non-quiet) output of the libtest harness.
+pub fn parse_output(stdout: &str) -> Vec<TestCase> {
+    let mut cases: Vec<TestCase> = stdout
+        .lines()
+        .filter_map(|line| {
+            let (name, result) = line.strip_prefix("test ")?.rsplit_once(" ... ")?;
+            let outcome = match result.trim() {
+                "ok" => Outcome::Passed,
+                "FAILED" => Outcome::Failed {
+                    expected: None,
+                    actual: None,
+                    message: String::new(),
+                },
+                r if r.starts_with("ignored") => Outcome::Ignored,
+                _ => return None,
+            };
+            Some(TestCase {
+                name: name.trim().to_string(),
+                outcome,
+            })
+        })
+        .collect();
+
+    // failures are described in sections like `---- tests::test_part_one stdout ----`.
+    for section in stdout.split("\n---- ").skip(1) {
+        let Some((name, details)) = section.split_once(" stdout ----") else {
+            continue;
+        };
+        let Some(case) = cases.iter_mut().find(|c| c.name == name) else {
+            continue;
+        };
+
+        let field = |prefix: &str| {
+            details
+                .lines()
+                .find_map(|l| l.trim_start().strip_prefix(prefix))
+                .map(|v| v.trim().to_string())
+        };
+
+        // the panic message follows the line with the location of the panic.
+        let message = details
+            .lines()
+            .skip_while(|l| !l.contains("panicked at"))
+            .nth(1)
+            .unwrap_or_default()
+            .trim()
+            .to_string();
+
+        // failures because of a missing or empty example are not real failures.
+        case.outcome = if message.starts_with(MISSING_FILE_MESSAGE) {
+            Outcome::Skipped("example is missing".into())
+        } else if details.lines().any(|l| l.starts_with(EMPTY_FILE_MESSAGE)) {
+            Outcome::Skipped("example is empty".into())
+        } else {
+            Outcome::Failed {
+                expected: field("right: "),
+                actual: field("left: "),
+                message,
+            }
+        };
+    }
+
+    cases
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Renders a line per test of a day.
+pub fn render(day: Day, cases: &[TestCase]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "{ANSI_BOLD}Day {day}{ANSI_RESET}");
+
+    if cases.is_empty() {
+        let _ = writeln!(out, "  {ANSI_ITALIC}no tests{ANSI_RESET}");
+    }
+
+    for case in cases {
+        let label = match case.part() {
+            Some(part) => format!("Part {part}"),
+            None => case
+                .name
+                .rsplit("::")
+                .next()
+                .unwrap_or(&case.name)
+                .to_string(),
+        };
+
+        let result = match &case.outcome {
+            Outcome::Passed => "✔ passed".to_string(),
+            Outcome::Ignored => format!("{ANSI_ITALIC}- ignored{ANSI_RESET}"),
+            Outcome::Skipped(reason) => format!("{ANSI_ITALIC}- skipped, {reason}{ANSI_RESET}"),
+            Outcome::Failed {
+                expected: Some(expected),
+                actual: Some(actual),
+                ..
+            } => format!(
+                "✖ failed, expected {ANSI_BOLD}{expected}{ANSI_RESET} but got {ANSI_BOLD}{actual}{ANSI_RESET}"
+            ),
+            Outcome::Failed { message, .. } => format!("✖ failed: {message}"),
+        };
+
+        let _ = writeln!(out, "  {label:<8}{result}");
+    }
+
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_output, Outcome};
+
+    const OUTPUT: &str = "
+running 4 tests
+test tests::test_part_one ... FAILED
+test tests::test_part_two ... ok
+test tests::test_parse ... FAILED
+test tests::test_part_two_second_example ... FAILED
+
+failures:
+
+---- tests::test_part_one stdout ----
+
+thread 'tests::test_part_one' (27178) panicked at src/bin/13.rs:4:26:
+assertion `left == right` failed
+  left: Some(3)
+ right: None
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+---- tests::test_parse stdout ----
+
+thread 'tests::test_parse' (27179) panicked at src/lib.rs:8:68:
+could not open input file: Os { code: 2, kind: NotFound, message: \"No such file or directory\" }
+
+---- tests::test_part_two_second_example stdout ----
+input file is empty: /aoc/data/examples/13-2.txt
+
+thread 'tests::test_part_two_second_example' (27180) panicked at src/bin/13.rs:9:26:
+assertion `left == right` failed
+  left: None
+ right: Some(4)
+
+failures:
+    tests::test_part_one
+    tests::test_parse
+    tests::test_part_two_second_example
+
+test result: FAILED. 1 passed; 3 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.01s
+";
+
+    #[test]
+    fn parses_test_results() {
+        let cases = parse_output(OUTPUT);
+        assert_eq!(cases.len(), 4);
+
+        assert_eq!(cases[0].part(), Some(1));
+        assert_eq!(
+            cases[0].outcome,
+            Outcome::Failed {
+                expected: Some("None".into()),
+                actual: Some("Some(3)".into()),
+                message: "assertion `left == right` failed".into(),
+            }
+        );
+
+        assert_eq!(cases[1].part(), Some(2));
+        assert_eq!(cases[1].outcome, Outcome::Passed);
+
+        assert_eq!(cases[2].part(), None);
+    }
+
+    #[test]
+    fn skips_missing_and_empty_examples() {
+        let cases = parse_output(OUTPUT);
+
+        // a real failure is not skipped, even if another test of the day reads an empty example.
+        assert!(matches!(cases[0].outcome, Outcome::Failed { .. }));
+        assert_eq!(
+            cases[2].outcome,
+            Outcome::Skipped("example is missing".into())
+        );
+        assert_eq!(
+            cases[3].outcome,
+            Outcome::Skipped("example is empty".into())
+        );
+    }
+}
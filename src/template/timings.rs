@@ -1,9 +1,11 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fs, io::Error, path::Path, str::FromStr};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{config, Day};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+fn get_file_path() -> String {
+    config::get().data_file("timings.json")
+}
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
@@ -25,13 +27,18 @@ impl Timings {
     /// Dehydrate timings to a JSON file.
     pub fn store_file(&self) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(get_file_path())?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
     pub fn read_from_file() -> Self {
-        let s = fs::read_to_string(TIMINGS_FILE_PATH)
+        let path = get_file_path();
+        if !Path::new(&path).exists() {
+            return Timings::default();
+        }
+
+        let s = fs::read_to_string(&path)
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from);
 